@@ -1,10 +1,12 @@
 // Standard type conversions
-// 
+//
 // Provides utility functions for standard Rust type conversions used across adapters
 
+use crate::ops::sqrt;
+
 /// Normalize a vector to unit length
 pub fn normalize_vec(v: &[f64]) -> Vec<f64> {
-    let magnitude: f64 = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let magnitude: f64 = sqrt(v.iter().map(|x| x * x).sum::<f64>());
     if magnitude < 1e-10 {
         return v.to_vec(); // Return original if zero vector
     }
@@ -32,6 +34,40 @@ pub fn vec_dot(a: &[f64], b: &[f64]) -> Result<f64, String> {
     Ok(a.iter().zip(b.iter()).map(|(x, y)| x * y).sum())
 }
 
+/// Calculate Euclidean norm (magnitude) of a vector
+pub fn vec_norm(v: &[f64]) -> f64 {
+    sqrt(v.iter().map(|x| x * x).sum::<f64>())
+}
+
+/// Calculate cross product of two 3-vectors
+pub fn vec_cross(a: &[f64], b: &[f64]) -> Result<Vec<f64>, String> {
+    if a.len() != 3 || b.len() != 3 {
+        return Err(format!(
+            "Cross product requires 3-vectors, got lengths {} and {}",
+            a.len(),
+            b.len()
+        ));
+    }
+    Ok(vec![
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ])
+}
+
+/// Project vector `a` onto vector `b`
+///
+/// proj_b(a) = (a·b / b·b) · b
+pub fn vec_project_on(a: &[f64], b: &[f64]) -> Result<Vec<f64>, String> {
+    let dot_ab = vec_dot(a, b)?;
+    let dot_bb = vec_dot(b, b)?;
+    if dot_bb.abs() < 1e-10 {
+        return Err("Cannot project onto the zero vector".to_string());
+    }
+    let scale = dot_ab / dot_bb;
+    Ok(vec_scale(b, scale))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,4 +95,26 @@ mod tests {
         let result = vec_dot(&a, &b).unwrap();
         assert_eq!(result, 32.0); // 1*4 + 2*5 + 3*6 = 4 + 10 + 18 = 32
     }
+
+    #[test]
+    fn test_vec_norm() {
+        let v = vec![3.0, 4.0];
+        assert!((vec_norm(&v) - 5.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_vec_cross() {
+        let a = vec![1.0, 0.0, 0.0];
+        let b = vec![0.0, 1.0, 0.0];
+        let result = vec_cross(&a, &b).unwrap();
+        assert_eq!(result, vec![0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_vec_project_on() {
+        let a = vec![3.0, 4.0];
+        let b = vec![1.0, 0.0];
+        let projection = vec_project_on(&a, &b).unwrap();
+        assert_eq!(projection, vec![3.0, 0.0]);
+    }
 }
@@ -5,9 +5,20 @@
 
 use crate::braid_group::Braid;
 use crate::knot_invariants::{KnotInvariants, calculate_writhe, calculate_crossing_number};
+use crate::knot_identification;
 use crate::polynomial::Polynomial;
 use flutter_rust_bridge::frb;
 
+/// Exact Laurent-polynomial result for FFI: parallel `exponents`/`coefficients`
+/// vectors (lowest exponent first) instead of a from-zero `Vec<f64>`, so Dart
+/// sees the real ±-degree support and exact integer coefficients of the
+/// Jones and Alexander polynomials rather than a truncated float array.
+#[derive(Debug, Clone)]
+pub struct LaurentPolynomialResult {
+    pub exponents: Vec<i32>,
+    pub coefficients: Vec<i64>,
+}
+
 /// Result type for knot generation (FFI-compatible)
 #[derive(Debug, Clone)]
 pub struct KnotResult {
@@ -48,15 +59,15 @@ pub fn generate_knot_from_braid(braid_data: Vec<f64>) -> Result<KnotResult, Stri
     }
     
     // Calculate invariants
-    let invariants = KnotInvariants::from_braid(&braid);
+    let invariants = KnotInvariants::from_braid(&braid)?;
     
     // Convert to output format
     let knot_data = vec![number_of_strands as f64]; // Simplified representation
     
     Ok(KnotResult {
         knot_data,
-        jones_polynomial: invariants.jones_polynomial.to_vec(),
-        alexander_polynomial: invariants.alexander_polynomial.to_vec(),
+        jones_polynomial: invariants.jones_polynomial.coefficients_f64(),
+        alexander_polynomial: invariants.alexander_polynomial.coefficients_f64(),
         crossing_number: invariants.crossing_number,
         writhe: invariants.writhe,
     })
@@ -87,8 +98,8 @@ pub fn calculate_jones_polynomial(braid_data: Vec<f64>) -> Result<Vec<f64>, Stri
         i += 2;
     }
     
-    let invariants = KnotInvariants::from_braid(&braid);
-    Ok(invariants.jones_polynomial.to_vec())
+    let invariants = KnotInvariants::from_braid(&braid)?;
+    Ok(invariants.jones_polynomial.coefficients_f64())
 }
 
 /// Calculate Alexander polynomial from braid data
@@ -116,8 +127,113 @@ pub fn calculate_alexander_polynomial(braid_data: Vec<f64>) -> Result<Vec<f64>,
         i += 2;
     }
     
-    let invariants = KnotInvariants::from_braid(&braid);
-    Ok(invariants.alexander_polynomial.to_vec())
+    let invariants = KnotInvariants::from_braid(&braid)?;
+    Ok(invariants.alexander_polynomial.coefficients_f64())
+}
+
+/// Calculate the Jones polynomial from braid data, exactly
+///
+/// Input: braid_data as flat vector [strands, crossing1_strand, crossing1_over, ...]
+/// Output: parallel (exponents, coefficients) vectors, lowest exponent first
+#[frb(sync)]
+pub fn calculate_jones_polynomial_exact(braid_data: Vec<f64>) -> Result<LaurentPolynomialResult, String> {
+    if braid_data.is_empty() {
+        return Err("Braid data cannot be empty".to_string());
+    }
+
+    let number_of_strands = braid_data[0] as usize;
+    let mut braid = Braid::new(number_of_strands);
+
+    let mut i = 1;
+    while i < braid_data.len() {
+        if i + 1 >= braid_data.len() {
+            break;
+        }
+        let strand = braid_data[i] as usize;
+        let is_over = braid_data[i + 1] > 0.5;
+        braid.add_crossing(strand, is_over)?;
+        i += 2;
+    }
+
+    let invariants = KnotInvariants::from_braid(&braid)?;
+    let (exponents, coefficients) = invariants.jones_polynomial.exponents_and_coefficients();
+    Ok(LaurentPolynomialResult { exponents, coefficients })
+}
+
+/// Calculate the Alexander polynomial from braid data, exactly
+///
+/// Input: braid_data as flat vector [strands, crossing1_strand, crossing1_over, ...]
+/// Output: parallel (exponents, coefficients) vectors, lowest exponent first
+#[frb(sync)]
+pub fn calculate_alexander_polynomial_exact(braid_data: Vec<f64>) -> Result<LaurentPolynomialResult, String> {
+    if braid_data.is_empty() {
+        return Err("Braid data cannot be empty".to_string());
+    }
+
+    let number_of_strands = braid_data[0] as usize;
+    let mut braid = Braid::new(number_of_strands);
+
+    let mut i = 1;
+    while i < braid_data.len() {
+        if i + 1 >= braid_data.len() {
+            break;
+        }
+        let strand = braid_data[i] as usize;
+        let is_over = braid_data[i + 1] > 0.5;
+        braid.add_crossing(strand, is_over)?;
+        i += 2;
+    }
+
+    let invariants = KnotInvariants::from_braid(&braid)?;
+    let (exponents, coefficients) = invariants.alexander_polynomial.exponents_and_coefficients();
+    Ok(LaurentPolynomialResult { exponents, coefficients })
+}
+
+/// Khovanov homology as a Poincaré polynomial: parallel vectors of nonzero
+/// `(homological_degree, quantum_degree, free_rank)` triples
+#[derive(Debug, Clone)]
+pub struct KhovanovHomologyResult {
+    pub homological_degrees: Vec<i32>,
+    pub quantum_degrees: Vec<i32>,
+    pub free_ranks: Vec<usize>,
+}
+
+/// Calculate the Khovanov homology of a braid closure
+///
+/// Input: braid_data as flat vector [strands, crossing1_strand, crossing1_over, ...]
+/// Output: a Poincaré polynomial, as parallel `(homological_degree,
+/// quantum_degree, free_rank)` vectors over the homology's nonzero bidegrees
+#[frb(sync)]
+pub fn calculate_khovanov_homology(braid_data: Vec<f64>) -> Result<KhovanovHomologyResult, String> {
+    if braid_data.is_empty() {
+        return Err("Braid data cannot be empty".to_string());
+    }
+
+    let number_of_strands = braid_data[0] as usize;
+    let mut braid = Braid::new(number_of_strands);
+
+    let mut i = 1;
+    while i < braid_data.len() {
+        if i + 1 >= braid_data.len() {
+            break;
+        }
+        let strand = braid_data[i] as usize;
+        let is_over = braid_data[i + 1] > 0.5;
+        braid.add_crossing(strand, is_over)?;
+        i += 2;
+    }
+
+    let homology = crate::khovanov::khovanov_homology(&braid);
+    let mut homological_degrees = Vec::with_capacity(homology.groups.len());
+    let mut quantum_degrees = Vec::with_capacity(homology.groups.len());
+    let mut free_ranks = Vec::with_capacity(homology.groups.len());
+    for ((i_degree, j_degree), group) in &homology.groups {
+        homological_degrees.push(*i_degree);
+        quantum_degrees.push(*j_degree);
+        free_ranks.push(group.free_rank);
+    }
+
+    Ok(KhovanovHomologyResult { homological_degrees, quantum_degrees, free_ranks })
 }
 
 /// Calculate topological compatibility between two knots
@@ -158,8 +274,8 @@ pub fn calculate_topological_compatibility(
     }
     
     // Calculate invariants
-    let invariants_a = KnotInvariants::from_braid(&braid_a);
-    let invariants_b = KnotInvariants::from_braid(&braid_b);
+    let invariants_a = KnotInvariants::from_braid(&braid_a)?;
+    let invariants_b = KnotInvariants::from_braid(&braid_b)?;
     
     // Calculate compatibility
     Ok(invariants_a.topological_compatibility(&invariants_b))
@@ -221,6 +337,47 @@ pub fn calculate_crossing_number_from_braid(braid_data: Vec<f64>) -> Result<usiz
     Ok(calculate_crossing_number(&braid))
 }
 
+/// Result type for knot identification (FFI-compatible)
+#[derive(Debug, Clone)]
+pub struct KnotIdentificationResult {
+    pub name: String,
+    pub crossing_number: usize,
+    pub ambiguous: bool,
+}
+
+/// Identify a braid's knot type against the built-in Rolfsen table
+///
+/// Input: braid_data as flat vector [strands, crossing1_strand, crossing1_over, ...]
+/// Output: the matched knot's name (e.g. "3_1"), or an error if no table
+/// entry's invariants match (see `knot_identification::identify_knot`)
+#[frb(sync)]
+pub fn identify_knot_from_braid(braid_data: Vec<f64>) -> Result<KnotIdentificationResult, String> {
+    if braid_data.is_empty() {
+        return Err("Braid data cannot be empty".to_string());
+    }
+
+    let number_of_strands = braid_data[0] as usize;
+    let mut braid = Braid::new(number_of_strands);
+
+    let mut i = 1;
+    while i < braid_data.len() {
+        if i + 1 >= braid_data.len() {
+            break;
+        }
+        let strand = braid_data[i] as usize;
+        let is_over = braid_data[i + 1] > 0.5;
+        braid.add_crossing(strand, is_over)?;
+        i += 2;
+    }
+
+    let identification = knot_identification::identify_knot(&braid)?;
+    Ok(KnotIdentificationResult {
+        name: identification.name,
+        crossing_number: identification.crossing_number,
+        ambiguous: identification.ambiguous,
+    })
+}
+
 /// Evaluate polynomial at a point
 /// 
 /// Input: coefficients (lowest degree first), x value
@@ -269,6 +426,43 @@ pub fn calculate_knot_energy_from_points(knot_points: Vec<f64>) -> Result<f64, S
     Ok(crate::knot_energy::calculate_knot_energy(&points))
 }
 
+/// Calculate knot energy from a B-spline representation (control points + knot vector)
+///
+/// Input: control_points as [x1, y1, z1, x2, y2, z2, ...], knot_vector, and
+/// the number of samples to densely evaluate the spline at (see
+/// `knot_spline::sample_curve`). The spline's degree is inferred from
+/// `knot_vector.len() == control_points.len()/3 + degree + 1`.
+/// Output: Energy value on the refined (densely sampled) curve
+#[frb(sync)]
+pub fn calculate_knot_energy_from_spline(
+    control_points: Vec<f64>,
+    knot_vector: Vec<f64>,
+    samples: usize,
+) -> Result<f64, String> {
+    if control_points.len() % 3 != 0 {
+        return Err("Control points must be a multiple of 3 (x, y, z coordinates)".to_string());
+    }
+
+    use nalgebra::DVector;
+    let num_control_points = control_points.len() / 3;
+    let mut points = Vec::with_capacity(num_control_points);
+    for i in 0..num_control_points {
+        let x = control_points[i * 3];
+        let y = control_points[i * 3 + 1];
+        let z = control_points[i * 3 + 2];
+        points.push(DVector::from_vec(vec![x, y, z]));
+    }
+
+    if knot_vector.len() < num_control_points + 1 {
+        return Err("Knot vector is too short for the given control points".to_string());
+    }
+    let degree = knot_vector.len() - num_control_points - 1;
+
+    let curve = crate::curve::BSplineCurve::new(points, knot_vector, degree)?;
+    let samples = crate::knot_spline::sample_curve(&curve, samples);
+    Ok(crate::knot_energy::calculate_knot_energy(&samples))
+}
+
 /// Calculate knot stability from knot points
 /// 
 /// Input: knot_points as [x1, y1, z1, x2, y2, z2, ...]
@@ -377,6 +571,59 @@ mod tests {
         assert!(!alexander.is_empty());
     }
 
+    #[test]
+    fn test_calculate_jones_polynomial_exact_trefoil() {
+        // sigma_1^3 on 2 strands (positive crossings) closes to the
+        // right-handed trefoil: Jones = t + t^3 - t^4
+        let braid_data = vec![2.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0];
+        let result = calculate_jones_polynomial_exact(braid_data).unwrap();
+
+        let by_exponent: std::collections::HashMap<i32, i64> =
+            result.exponents.iter().copied().zip(result.coefficients.iter().copied()).collect();
+        assert_eq!(by_exponent.get(&1), Some(&1));
+        assert_eq!(by_exponent.get(&3), Some(&1));
+        assert_eq!(by_exponent.get(&4), Some(&-1));
+    }
+
+    #[test]
+    fn test_calculate_alexander_polynomial_exact_trefoil() {
+        // sigma_1^3 on 2 strands closes to a trefoil: Delta(t) = t - 1 + t^-1
+        let braid_data = vec![2.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0];
+        let result = calculate_alexander_polynomial_exact(braid_data).unwrap();
+
+        let by_exponent: std::collections::HashMap<i32, i64> =
+            result.exponents.iter().copied().zip(result.coefficients.iter().copied()).collect();
+        assert_eq!(by_exponent.get(&-1), Some(&1));
+        assert_eq!(by_exponent.get(&0), Some(&-1));
+        assert_eq!(by_exponent.get(&1), Some(&1));
+    }
+
+    #[test]
+    fn test_calculate_khovanov_homology_trefoil() {
+        let braid_data = vec![2.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0];
+        let result = calculate_khovanov_homology(braid_data).unwrap();
+
+        let bidegrees: std::collections::HashSet<(i32, i32)> = result
+            .homological_degrees
+            .iter()
+            .copied()
+            .zip(result.quantum_degrees.iter().copied())
+            .collect();
+        assert!(bidegrees.contains(&(0, 1)));
+        assert!(bidegrees.contains(&(0, 3)));
+        assert!(bidegrees.contains(&(2, 5)));
+        assert!(bidegrees.contains(&(3, 9)));
+    }
+
+    #[test]
+    fn test_identify_knot_from_braid_trefoil() {
+        let braid_data = vec![2.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0];
+        let result = identify_knot_from_braid(braid_data).unwrap();
+        assert_eq!(result.name, "3_1");
+        assert_eq!(result.crossing_number, 3);
+        assert!(!result.ambiguous);
+    }
+
     #[test]
     fn test_calculate_topological_compatibility() {
         let braid_data_a = vec![3.0, 0.0, 1.0];
@@ -427,6 +674,20 @@ mod tests {
         assert!(energy < 1.0); // Straight line should have low energy
     }
 
+    #[test]
+    fn test_calculate_knot_energy_from_spline() {
+        // Straight-line control points with a clamped cubic knot vector
+        // (1 interior span): should have low energy, same as the polyline case.
+        let control_points = vec![
+            0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 2.0, 0.0, 0.0, 3.0, 0.0, 0.0,
+        ];
+        let knot_vector = vec![0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0];
+
+        let energy = calculate_knot_energy_from_spline(control_points, knot_vector, 20).unwrap();
+        assert!(energy >= 0.0);
+        assert!(energy < 1.0);
+    }
+
     #[test]
     fn test_calculate_knot_stability_from_points() {
         let points = vec![
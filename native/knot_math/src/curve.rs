@@ -0,0 +1,273 @@
+// B-spline curve representation
+//
+// Implements a parametric B-spline curve with analytic derivatives, used to
+// replace raw finite-difference approximations of r'(s) and r''(s) on point
+// clouds with exact derivatives of a fitted curve.
+
+use nalgebra::{DMatrix, DVector};
+
+/// A B-spline curve of a given degree, defined by control points and a knot vector
+///
+/// Basis functions are evaluated via the Cox-de Boor recursion:
+/// N_{i,0}(s) = 1 if knot_i <= s < knot_{i+1} else 0
+/// N_{i,p}(s) = (s - knot_i)/(knot_{i+p} - knot_i) * N_{i,p-1}(s)
+///            + (knot_{i+p+1} - s)/(knot_{i+p+1} - knot_{i+1}) * N_{i+1,p-1}(s)
+#[derive(Debug, Clone)]
+pub struct BSplineCurve {
+    control_points: Vec<DVector<f64>>,
+    knots: Vec<f64>,
+    degree: usize,
+}
+
+impl BSplineCurve {
+    /// Create a new B-spline curve from control points, knot vector, and degree
+    ///
+    /// Requires `knots.len() == control_points.len() + degree + 1`
+    pub fn new(control_points: Vec<DVector<f64>>, knots: Vec<f64>, degree: usize) -> Result<Self, String> {
+        if control_points.is_empty() {
+            return Err("Control points cannot be empty".to_string());
+        }
+        if knots.len() != control_points.len() + degree + 1 {
+            return Err(format!(
+                "Knot vector length {} must equal control_points.len() + degree + 1 = {}",
+                knots.len(),
+                control_points.len() + degree + 1
+            ));
+        }
+        Ok(BSplineCurve {
+            control_points,
+            knots,
+            degree,
+        })
+    }
+
+    /// Parameter domain [s_min, s_max) of the curve
+    pub fn domain(&self) -> (f64, f64) {
+        (self.knots[self.degree], self.knots[self.knots.len() - self.degree - 1])
+    }
+
+    /// Cox-de Boor recursion for basis function N_{i,p}(s)
+    fn basis(&self, i: usize, p: usize, s: f64) -> f64 {
+        if p == 0 {
+            let in_span = s >= self.knots[i] && s < self.knots[i + 1];
+            // Include the right endpoint of the domain in the last non-empty
+            // span (a clamped knot vector ends in zero-length spans)
+            let last_knot = self.knots[self.knots.len() - 1];
+            let at_end = (s - last_knot).abs() < 1e-12
+                && self.knots[i] < self.knots[i + 1]
+                && (self.knots[i + 1] - last_knot).abs() < 1e-12;
+            return if in_span || at_end { 1.0 } else { 0.0 };
+        }
+
+        let left_denom = self.knots[i + p] - self.knots[i];
+        let left = if left_denom.abs() < 1e-12 {
+            0.0
+        } else {
+            (s - self.knots[i]) / left_denom * self.basis(i, p - 1, s)
+        };
+
+        let right_denom = self.knots[i + p + 1] - self.knots[i + 1];
+        let right = if right_denom.abs() < 1e-12 {
+            0.0
+        } else {
+            (self.knots[i + p + 1] - s) / right_denom * self.basis(i + 1, p - 1, s)
+        };
+
+        left + right
+    }
+
+    /// Evaluate the curve at parameter s
+    pub fn eval(&self, s: f64) -> DVector<f64> {
+        let (s_min, s_max) = self.domain();
+        let s = s.clamp(s_min, s_max);
+        let dim = self.control_points[0].len();
+        let mut result = DVector::zeros(dim);
+
+        for (i, point) in self.control_points.iter().enumerate() {
+            let n_i = self.basis(i, self.degree, s);
+            if n_i != 0.0 {
+                result += point * n_i;
+            }
+        }
+
+        result
+    }
+
+    /// Build the analytic derivative curve (degree p-1)
+    ///
+    /// New control points: Delta_i = p * (P_{i+1} - P_i) / (knot_{i+p+1} - knot_{i+1})
+    /// New knot vector drops the first and last knot of the original.
+    pub fn derivative_curve(&self) -> Option<BSplineCurve> {
+        if self.degree == 0 {
+            return None;
+        }
+
+        let p = self.degree as f64;
+        let mut new_points = Vec::with_capacity(self.control_points.len() - 1);
+        for i in 0..self.control_points.len() - 1 {
+            let denom = self.knots[i + self.degree + 1] - self.knots[i + 1];
+            let delta = if denom.abs() < 1e-12 {
+                DVector::zeros(self.control_points[i].len())
+            } else {
+                (&self.control_points[i + 1] - &self.control_points[i]) * (p / denom)
+            };
+            new_points.push(delta);
+        }
+
+        let new_knots = self.knots[1..self.knots.len() - 1].to_vec();
+
+        Some(BSplineCurve {
+            control_points: new_points,
+            knots: new_knots,
+            degree: self.degree - 1,
+        })
+    }
+
+    /// Evaluate the `order`-th derivative at parameter s
+    ///
+    /// Repeatedly differentiates the curve analytically via `derivative_curve`
+    /// and evaluates the result; returns the zero vector once the degree
+    /// collapses below the requested order.
+    pub fn derivative(&self, s: f64, order: usize) -> DVector<f64> {
+        if order == 0 {
+            return self.eval(s);
+        }
+        match self.derivative_curve() {
+            Some(d_curve) => d_curve.derivative(s, order - 1),
+            None => DVector::zeros(self.control_points[0].len()),
+        }
+    }
+
+    /// Fit a clamped cubic (or given degree) B-spline that interpolates the given sample points
+    ///
+    /// Uses chord-length parameterization for the sample parameters and the
+    /// standard averaging rule for interior knots, then solves the global
+    /// interpolation linear system N * P = points for the control points.
+    pub fn interpolate(points: &[DVector<f64>], degree: usize) -> Result<Self, String> {
+        let n = points.len();
+        if n < degree + 1 {
+            return Err(format!(
+                "Need at least {} points to interpolate a degree-{} B-spline",
+                degree + 1,
+                degree
+            ));
+        }
+
+        // Chord-length parameterization
+        let mut chord_lengths = vec![0.0; n];
+        let mut total_length = 0.0;
+        for i in 1..n {
+            total_length += (&points[i] - &points[i - 1]).norm();
+            chord_lengths[i] = total_length;
+        }
+        let params: Vec<f64> = if total_length.abs() < 1e-12 {
+            (0..n).map(|i| i as f64 / (n - 1) as f64).collect()
+        } else {
+            chord_lengths.iter().map(|&l| l / total_length).collect()
+        };
+
+        // Clamped knot vector via the standard averaging rule
+        let mut knots = vec![0.0; n + degree + 1];
+        for i in 0..=degree {
+            knots[i] = 0.0;
+            knots[n + degree - i] = 1.0;
+        }
+        for j in 1..(n - degree) {
+            let mut sum = 0.0;
+            for i in j..(j + degree) {
+                sum += params[i];
+            }
+            knots[j + degree] = sum / degree as f64;
+        }
+
+        let curve = BSplineCurve {
+            control_points: points.to_vec(),
+            knots,
+            degree,
+        };
+
+        // Solve N * P = points for control points (one linear system per coordinate)
+        let mut basis_matrix = DMatrix::<f64>::zeros(n, n);
+        for (row, &s) in params.iter().enumerate() {
+            for col in 0..n {
+                basis_matrix[(row, col)] = curve.basis(col, degree, s);
+            }
+        }
+
+        let dim = points[0].len();
+        let mut rhs = DMatrix::<f64>::zeros(n, dim);
+        for (row, point) in points.iter().enumerate() {
+            for col in 0..dim {
+                rhs[(row, col)] = point[col];
+            }
+        }
+
+        let decomposition = basis_matrix
+            .lu()
+            .solve(&rhs)
+            .ok_or_else(|| "Failed to solve B-spline interpolation system (singular matrix)".to_string())?;
+
+        let control_points = (0..n)
+            .map(|row| DVector::from_iterator(dim, (0..dim).map(|col| decomposition[(row, col)])))
+            .collect();
+
+        Ok(BSplineCurve {
+            control_points,
+            knots: curve.knots,
+            degree,
+        })
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    pub fn control_points(&self) -> &[DVector<f64>] {
+        &self.control_points
+    }
+
+    pub fn knots(&self) -> &[f64] {
+        &self.knots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quadratic_bspline_interpolates_endpoints() {
+        let points = vec![
+            DVector::from_vec(vec![0.0, 0.0, 0.0]),
+            DVector::from_vec(vec![1.0, 1.0, 0.0]),
+            DVector::from_vec(vec![2.0, 0.0, 0.0]),
+        ];
+
+        let curve = BSplineCurve::interpolate(&points, 2).unwrap();
+        let (s_min, s_max) = curve.domain();
+
+        let start = curve.eval(s_min);
+        let end = curve.eval(s_max);
+
+        assert!((start - &points[0]).norm() < 1e-8);
+        assert!((end - &points[points.len() - 1]).norm() < 1e-8);
+    }
+
+    #[test]
+    fn test_derivative_of_straight_line_is_constant() {
+        // Degree-1 spline along a straight line: first derivative constant, second derivative zero
+        let control_points = vec![
+            DVector::from_vec(vec![0.0, 0.0, 0.0]),
+            DVector::from_vec(vec![1.0, 0.0, 0.0]),
+            DVector::from_vec(vec![2.0, 0.0, 0.0]),
+        ];
+        let knots = vec![0.0, 0.0, 0.5, 1.0, 1.0];
+        let curve = BSplineCurve::new(control_points, knots, 1).unwrap();
+
+        let d1_mid = curve.derivative(0.25, 1);
+        let d2_mid = curve.derivative(0.25, 2);
+
+        assert!(d1_mid.norm() > 0.0);
+        assert!(d2_mid.norm() < 1e-8);
+    }
+}
@@ -0,0 +1,626 @@
+// Khovanov homology
+//
+// Categorifies the Jones polynomial via the same cube of resolutions used by
+// `knot_invariants::calculate_jones_polynomial`, but instead of collapsing
+// each state to a scalar Kauffman bracket term it assigns each state the
+// Frobenius algebra `A = Z[X]/(X^2)` tensored once per circle, with maps
+// between adjacent states given by the algebra's (co)multiplication. The
+// resulting bigraded chain complex's homology is the Khovanov homology, and
+// its graded Euler characteristic recovers the unnormalized Jones polynomial
+// `(q + q^-1)·V`.
+
+use crate::braid_group::Braid;
+use crate::polynomial::LaurentPolynomial;
+use crate::smith_normal_form;
+use rug::Integer;
+use std::collections::{BTreeSet, HashMap};
+
+/// Minimal union-find (disjoint set) over an arbitrary `usize` node space
+struct UnionFind {
+    parent: HashMap<usize, usize>,
+}
+
+impl UnionFind {
+    fn find(&mut self, x: usize) -> usize {
+        let parent = *self.parent.entry(x).or_insert(x);
+        if parent == x {
+            x
+        } else {
+            let root = self.find(parent);
+            self.parent.insert(x, root);
+            root
+        }
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (root_a, root_b) = (self.find(a), self.find(b));
+        if root_a != root_b {
+            self.parent.insert(root_a, root_b);
+        }
+    }
+}
+
+/// A circle (loop) in a fully-resolved diagram, identified by the
+/// braid-closure strand positions it passes through (`anchors`) and its raw
+/// node ids (`members`). A circle with no anchors is a "bubble": a loop
+/// created entirely by two nested smoothings at the same strand position,
+/// touching no closure strand.
+#[derive(Debug, Clone)]
+pub(crate) struct Circle {
+    anchors: BTreeSet<usize>,
+    members: BTreeSet<usize>,
+}
+
+/// The result of resolving a braid according to one state of the cube of resolutions
+pub(crate) struct Resolution {
+    pub(crate) circles: Vec<Circle>,
+    pub(crate) node_to_circle: HashMap<usize, usize>,
+    /// For each crossing, the two node ids occupying its two strand
+    /// positions immediately before that crossing was processed -- needed to
+    /// identify exactly which circles a single bit flip merges or splits.
+    pub(crate) before: Vec<(usize, usize)>,
+}
+
+/// Resolve `braid` according to `state` (one bit per crossing; `true` = 1-smoothing
+/// (merge), `false` = 0-smoothing (identity)) and group its nodes into circles.
+///
+/// Node ids are a fixed, state-independent scheme so that circles can be
+/// compared across adjacent states: anchors `0..strands` are the braid-closure
+/// strand positions, and crossing `k`'s merge (if active in `state`) always
+/// introduces the same two fresh ids `strands + 2*k` and `strands + 2*k + 1`,
+/// regardless of what any other crossing's bit is.
+pub(crate) fn resolve(braid: &Braid, state: &[bool]) -> Resolution {
+    let n = braid.strands();
+    let crossings = braid.get_crossings();
+
+    let mut uf = UnionFind { parent: HashMap::new() };
+    let mut connect: Vec<usize> = (0..n).collect();
+    let mut before = Vec::with_capacity(crossings.len());
+    let mut active: BTreeSet<usize> = (0..n).collect();
+
+    for (k, crossing) in crossings.iter().enumerate() {
+        let i = crossing.strand;
+        before.push((connect[i], connect[i + 1]));
+        if state[k] {
+            uf.union(connect[i], connect[i + 1]);
+            let (fresh_a, fresh_b) = (n + 2 * k, n + 2 * k + 1);
+            connect[i] = fresh_a;
+            connect[i + 1] = fresh_b;
+            active.insert(fresh_a);
+            active.insert(fresh_b);
+        }
+    }
+
+    for pos in 0..n {
+        uf.union(connect[pos], pos);
+    }
+
+    let mut root_to_circle: HashMap<usize, usize> = HashMap::new();
+    let mut circles: Vec<Circle> = Vec::new();
+    let mut node_to_circle: HashMap<usize, usize> = HashMap::new();
+
+    for &node in &active {
+        let root = uf.find(node);
+        let circle_idx = *root_to_circle.entry(root).or_insert_with(|| {
+            circles.push(Circle { anchors: BTreeSet::new(), members: BTreeSet::new() });
+            circles.len() - 1
+        });
+        circles[circle_idx].members.insert(node);
+        if node < n {
+            circles[circle_idx].anchors.insert(node);
+        }
+        node_to_circle.insert(node, circle_idx);
+    }
+
+    Resolution { circles, node_to_circle, before }
+}
+
+/// Deterministic ordering of a state's circles: anchor-touching circles
+/// first (by smallest anchor position), then bubbles (by smallest raw node
+/// id) -- gives each circle a stable tensor-factor slot for that state's generators.
+pub(crate) fn canonical_order(circles: &[Circle]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..circles.len()).collect();
+    order.sort_by_key(|&idx| {
+        let circle = &circles[idx];
+        match circle.anchors.iter().next() {
+            Some(&min_anchor) => (0, min_anchor),
+            None => (1, *circle.members.iter().next().expect("circle has no members")),
+        }
+    });
+    order
+}
+
+/// Find the single circle of `candidates` matching `target` (by anchor set if
+/// `target` touches any anchor, else by exact member set for a bubble),
+/// excluding indices already in `used`
+fn match_circle(target: &Circle, candidates: &[Circle], used: &mut BTreeSet<usize>) -> usize {
+    for (idx, candidate) in candidates.iter().enumerate() {
+        if used.contains(&idx) {
+            continue;
+        }
+        let matches = if target.anchors.is_empty() {
+            candidate.members == target.members
+        } else {
+            candidate.anchors == target.anchors
+        };
+        if matches {
+            used.insert(idx);
+            return idx;
+        }
+    }
+    panic!("no matching circle found across adjacent cube-of-resolutions states");
+}
+
+/// `1 ↦ 0`, `X ↦ 1`: Frobenius algebra `A = Z[X]/(X^2)` generator labels
+pub(crate) type Label = u8;
+
+/// Multiplication `m: A⊗A → A`: `1⊗1↦1`, `1⊗X,X⊗1↦X`, `X⊗X↦0`
+fn frobenius_multiply(a: Label, b: Label) -> Option<Label> {
+    match (a, b) {
+        (0, 0) => Some(0),
+        (0, 1) | (1, 0) => Some(1),
+        (1, 1) => None,
+        _ => unreachable!("label must be 0 or 1"),
+    }
+}
+
+/// Comultiplication `Δ: A → A⊗A`: `1↦1⊗X + X⊗1`, `X↦X⊗X`
+fn frobenius_comultiply(a: Label) -> Vec<(Label, Label)> {
+    match a {
+        0 => vec![(0, 1), (1, 0)],
+        1 => vec![(1, 1)],
+        _ => unreachable!("label must be 0 or 1"),
+    }
+}
+
+/// `+1` for label `1` (the algebra's unit), `-1` for label `X`
+pub(crate) fn label_degree(label: Label) -> i32 {
+    if label == 0 { 1 } else { -1 }
+}
+
+/// One basis element of the Khovanov chain complex: a resolution state
+/// together with a label assignment to each of its circles, in canonical order
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KhovanovGenerator {
+    pub(crate) state: Vec<bool>,
+    pub(crate) labels: Vec<Label>,
+}
+
+/// A single bigraded homology group `H^{i,j}`
+#[derive(Debug, Clone)]
+pub struct HomologyGroup {
+    pub free_rank: usize,
+    /// Torsion coefficients (e.g. `2` for a `Z/2` summand), from
+    /// `smith_normal_form::homology_group`'s exact integer computation.
+    pub torsion: Vec<u64>,
+}
+
+/// The Khovanov homology of a braid closure, as a sparse table of nonzero bidegrees
+#[derive(Debug, Clone)]
+pub struct KhovanovHomology {
+    pub groups: Vec<((i32, i32), HomologyGroup)>,
+}
+
+impl KhovanovHomology {
+    /// Graded Euler characteristic `Σ (-1)^i q^j · free_rank`
+    ///
+    /// By the Euler-Poincaré principle this equals the alternating sum of
+    /// chain group dimensions, which (with the `n_-` and `n_+ - 2n_-` degree
+    /// shifts) is the unnormalized Jones polynomial `(q + q^-1)·V(q^2)`
+    /// (torsion summands don't affect it, so computing it from homology free
+    /// ranks rather than the raw chain complex is a meaningful self-check).
+    pub fn graded_euler_characteristic(&self) -> LaurentPolynomial {
+        let mut by_degree: std::collections::BTreeMap<i64, i64> = std::collections::BTreeMap::new();
+        for ((i, j), group) in &self.groups {
+            let sign: i64 = if i % 2 == 0 { 1 } else { -1 };
+            *by_degree.entry(*j as i64).or_insert(0) += sign * group.free_rank as i64;
+        }
+
+        if by_degree.is_empty() {
+            return LaurentPolynomial::new(0, vec![0]);
+        }
+
+        let min_degree = *by_degree.keys().next().unwrap();
+        let max_degree = *by_degree.keys().next_back().unwrap();
+        let coefficients: Vec<i64> = (min_degree..=max_degree)
+            .map(|degree| *by_degree.get(&degree).unwrap_or(&0))
+            .collect();
+        LaurentPolynomial::new(min_degree, coefficients)
+    }
+}
+
+/// The differential out of every generator, as a list of (target generator, signed coefficient) pairs
+type Differential = HashMap<KhovanovGenerator, Vec<(KhovanovGenerator, i64)>>;
+
+/// Build the Khovanov chain complex: every basis generator with its
+/// bigrading, and the differential mapping each generator to its images one
+/// resolution step up the cube
+fn build_complex(braid: &Braid) -> (Vec<(KhovanovGenerator, i32, i32)>, Differential) {
+    let crossings = braid.get_crossings();
+    let n = crossings.len();
+    let n_plus = crossings.iter().filter(|c| c.is_over).count() as i32;
+    let n_minus = n as i32 - n_plus;
+
+    let mut basis = Vec::new();
+    let mut differential: Differential = HashMap::new();
+
+    for bits in 0..(1u64 << n) {
+        let state: Vec<bool> = (0..n).map(|k| (bits >> k) & 1 == 1).collect();
+        let resolution = resolve(braid, &state);
+        let order = canonical_order(&resolution.circles);
+        let num_circles = order.len();
+        let r = state.iter().filter(|&&b| b).count() as i32;
+        let i_degree = r - n_minus;
+
+        for labels_bits in 0..(1u64 << num_circles) {
+            let labels: Vec<Label> = (0..num_circles).map(|p| ((labels_bits >> p) & 1) as Label).collect();
+            let degree_sum: i32 = labels.iter().map(|&l| label_degree(l)).sum();
+            let j_degree = degree_sum + r + n_plus - 2 * n_minus;
+            basis.push((KhovanovGenerator { state: state.clone(), labels: labels.clone() }, i_degree, j_degree));
+        }
+
+        for (k, &bit) in state.iter().enumerate() {
+            if bit {
+                continue;
+            }
+            let mut state1 = state.clone();
+            state1[k] = true;
+            let resolution1 = resolve(braid, &state1);
+            let order1 = canonical_order(&resolution1.circles);
+
+            let (before_a, before_b) = resolution.before[k];
+            let src_a = resolution.node_to_circle[&before_a];
+            let src_b = resolution.node_to_circle[&before_b];
+
+            // Sign from the standard Khovanov cube convention: (-1)^(number of 1s before position k)
+            let sign: i64 = if state[..k].iter().filter(|&&b| b).count() % 2 == 1 { -1 } else { 1 };
+
+            // The rest of this edge's structure (which circles merge or split,
+            // and how every other circle of `state` maps into `state1`) depends
+            // only on (state, k), not on any particular generator's labels, so
+            // it's computed once per edge rather than once per generator.
+            let edge = build_edge(&resolution, src_a, src_b, &resolution1, &order1, braid.strands(), k);
+
+            for labels_bits in 0..(1u64 << num_circles) {
+                let labels: Vec<Label> = (0..num_circles).map(|p| ((labels_bits >> p) & 1) as Label).collect();
+                let source = KhovanovGenerator { state: state.clone(), labels: labels.clone() };
+
+                let label_at = |circle_idx: usize| -> Label {
+                    let pos = order.iter().position(|&c| c == circle_idx).unwrap();
+                    labels[pos]
+                };
+
+                let mut images: Vec<(KhovanovGenerator, i64)> = Vec::new();
+
+                match &edge {
+                    CubeEdge::Merge { target_idx, other_map } => {
+                        if let Some(merged_label) = frobenius_multiply(label_at(src_a), label_at(src_b)) {
+                            let mut new_labels = vec![0u8; order1.len()];
+                            let target_pos = order1.iter().position(|&c| c == *target_idx).unwrap();
+                            new_labels[target_pos] = merged_label;
+                            for (&idx0, &idx1) in other_map {
+                                let pos1 = order1.iter().position(|&c| c == idx1).unwrap();
+                                new_labels[pos1] = label_at(idx0);
+                            }
+                            images.push((KhovanovGenerator { state: state1.clone(), labels: new_labels }, sign));
+                        }
+                    }
+                    CubeEdge::Split { idx_a, idx_b, other_map } => {
+                        for (label_a, label_b) in frobenius_comultiply(label_at(src_a)) {
+                            let mut new_labels = vec![0u8; order1.len()];
+                            new_labels[order1.iter().position(|&c| c == *idx_a).unwrap()] = label_a;
+                            new_labels[order1.iter().position(|&c| c == *idx_b).unwrap()] = label_b;
+                            for (&idx0, &idx1) in other_map {
+                                let pos1 = order1.iter().position(|&c| c == idx1).unwrap();
+                                new_labels[pos1] = label_at(idx0);
+                            }
+                            images.push((KhovanovGenerator { state: state1.clone(), labels: new_labels }, sign));
+                        }
+                    }
+                }
+
+                differential.entry(source).or_default().extend(images);
+            }
+        }
+    }
+
+    (basis, differential)
+}
+
+/// How one edge of the cube of resolutions acts on circles: either two
+/// circles of the source state merge into one circle of the target state, or
+/// one circle splits into two. `other_map` carries every unaffected circle's
+/// index in the source state to its corresponding index in the target state.
+pub(crate) enum CubeEdge {
+    Merge { target_idx: usize, other_map: HashMap<usize, usize> },
+    Split { idx_a: usize, idx_b: usize, other_map: HashMap<usize, usize> },
+}
+
+/// Determine the merge/split structure of the cube edge from `resolution`
+/// (bit `k` = 0) to `resolution1` (bit `k` = 1), given the two circles
+/// (`src_a`, `src_b`) occupying crossing `k`'s strand positions in `resolution`
+pub(crate) fn build_edge(
+    resolution: &Resolution,
+    src_a: usize,
+    src_b: usize,
+    resolution1: &Resolution,
+    order1: &[usize],
+    strands: usize,
+    k: usize,
+) -> CubeEdge {
+    if src_a != src_b {
+        let merged_anchors: BTreeSet<usize> =
+            resolution.circles[src_a].anchors.union(&resolution.circles[src_b].anchors).copied().collect();
+        let merged_members: BTreeSet<usize> =
+            resolution.circles[src_a].members.union(&resolution.circles[src_b].members).copied().collect();
+        let merged = Circle { anchors: merged_anchors, members: merged_members };
+
+        let mut used = BTreeSet::new();
+        let target_idx = match_circle(&merged, &resolution1.circles, &mut used);
+
+        let mut other_map = HashMap::new();
+        for (idx0, circle0) in resolution.circles.iter().enumerate() {
+            if idx0 == src_a || idx0 == src_b {
+                continue;
+            }
+            other_map.insert(idx0, match_circle(circle0, &resolution1.circles, &mut used));
+        }
+
+        CubeEdge::Merge { target_idx, other_map }
+    } else {
+        let src_circle = &resolution.circles[src_a];
+        let mut candidates: Vec<usize> = if !src_circle.anchors.is_empty() {
+            resolution1
+                .circles
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| !c.anchors.is_empty() && c.anchors.is_subset(&src_circle.anchors))
+                .map(|(idx, _)| idx)
+                .collect()
+        } else {
+            Vec::new()
+        };
+        if candidates.len() != 2 {
+            let mut allowed = src_circle.members.clone();
+            allowed.insert(strands + 2 * k);
+            allowed.insert(strands + 2 * k + 1);
+            candidates = resolution1
+                .circles
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| c.members.is_subset(&allowed))
+                .map(|(idx, _)| idx)
+                .collect();
+        }
+        candidates.sort_by_key(|&idx| order1.iter().position(|&c| c == idx).unwrap());
+        let (idx_a, idx_b) = (candidates[0], candidates[1]);
+
+        let mut used: BTreeSet<usize> = [idx_a, idx_b].into_iter().collect();
+        let mut other_map = HashMap::new();
+        for (idx0, circle0) in resolution.circles.iter().enumerate() {
+            if idx0 == src_a {
+                continue;
+            }
+            other_map.insert(idx0, match_circle(circle0, &resolution1.circles, &mut used));
+        }
+
+        CubeEdge::Split { idx_a, idx_b, other_map }
+    }
+}
+
+/// Build the integer matrix of the differential restricted to the edges
+/// from `sources` into `target_index`'s generators: row per source
+/// generator, column per target generator, entries the signed coefficient
+/// from `differential` (zero where there is no edge).
+fn differential_matrix(
+    sources: &[&KhovanovGenerator],
+    target_index: &HashMap<&KhovanovGenerator, usize>,
+    differential: &Differential,
+) -> Vec<Vec<Integer>> {
+    sources
+        .iter()
+        .map(|generator| {
+            let mut row = vec![Integer::from(0); target_index.len()];
+            if let Some(targets) = differential.get(*generator) {
+                for (target, coeff) in targets {
+                    if let Some(&col) = target_index.get(target) {
+                        row[col] += Integer::from(*coeff);
+                    }
+                }
+            }
+            row
+        })
+        .collect()
+}
+
+/// Compute the Khovanov homology of a braid closure
+///
+/// Builds the bigraded chain complex over the cube of resolutions and, for
+/// every bidegree `(i, j)`, hands the differentials in (`d^{i-1}: C_{i-1} ->
+/// C_i`) and out (`d^i: C_i -> C_{i+1}`) of that bidegree's generators to
+/// `smith_normal_form::homology_group`, which computes both the free rank
+/// and the exact integer torsion subgroup. Bidegrees with trivial homology
+/// (free rank 0 and no torsion) are omitted from the result.
+pub fn khovanov_homology(braid: &Braid) -> KhovanovHomology {
+    let (basis, differential) = build_complex(braid);
+
+    let mut by_bidegree: HashMap<(i32, i32), Vec<&KhovanovGenerator>> = HashMap::new();
+    for (generator, i, j) in &basis {
+        by_bidegree.entry((*i, *j)).or_default().push(generator);
+    }
+
+    let mut groups = Vec::new();
+    let bidegrees: BTreeSet<(i32, i32)> = by_bidegree.keys().copied().collect();
+
+    for &(i, j) in &bidegrees {
+        let current = &by_bidegree[&(i, j)];
+        let next = by_bidegree.get(&(i + 1, j));
+        let prev = by_bidegree.get(&(i - 1, j));
+
+        let current_index: HashMap<&KhovanovGenerator, usize> =
+            current.iter().enumerate().map(|(idx, g)| (*g, idx)).collect();
+        let next_index: HashMap<&KhovanovGenerator, usize> = next
+            .map(|v| v.iter().enumerate().map(|(idx, g)| (*g, idx)).collect())
+            .unwrap_or_default();
+
+        let d_out = differential_matrix(current, &next_index, &differential);
+        let d_in = match prev {
+            Some(prev_generators) => differential_matrix(prev_generators, &current_index, &differential),
+            None => Vec::new(),
+        };
+
+        let group = smith_normal_form::homology_group(&d_in, &d_out, current.len());
+        if group.free_rank > 0 || !group.torsion.is_empty() {
+            groups.push(((i, j), HomologyGroup { free_rank: group.free_rank, torsion: group.torsion }));
+        }
+    }
+
+    KhovanovHomology { groups }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_khovanov_homology_unknot() {
+        let braid = Braid::new(2);
+        let homology = khovanov_homology(&braid);
+
+        // Unknot: Kh is Z at (0,1) and Z at (0,-1)
+        let ranks: HashMap<(i32, i32), usize> =
+            homology.groups.iter().map(|(deg, group)| (*deg, group.free_rank)).collect();
+        assert_eq!(ranks.get(&(0, 1)), Some(&1));
+        assert_eq!(ranks.get(&(0, -1)), Some(&1));
+        assert_eq!(homology.groups.len(), 2);
+    }
+
+    #[test]
+    fn test_khovanov_homology_trefoil_matches_known_table() {
+        // sigma_1^3 on 2 strands closes to a trefoil
+        let mut braid = Braid::new(2);
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+
+        let homology = khovanov_homology(&braid);
+        let groups: HashMap<(i32, i32), &HomologyGroup> = homology.groups.iter().map(|(deg, group)| (*deg, group)).collect();
+
+        // Known free (non-torsion) Khovanov homology of the trefoil
+        assert_eq!(groups.get(&(0, 1)).map(|g| g.free_rank), Some(1));
+        assert_eq!(groups.get(&(0, 3)).map(|g| g.free_rank), Some(1));
+        assert_eq!(groups.get(&(2, 5)).map(|g| g.free_rank), Some(1));
+        assert_eq!(groups.get(&(3, 9)).map(|g| g.free_rank), Some(1));
+        // The trefoil's known Z/2 torsion summand at (3, 7), with free rank 0
+        let torsion_group = groups.get(&(3, 7)).expect("(3, 7) should carry the Z/2 torsion summand");
+        assert_eq!(torsion_group.free_rank, 0);
+        assert_eq!(torsion_group.torsion, vec![2]);
+    }
+
+    #[test]
+    fn test_graded_euler_characteristic_trefoil() {
+        let mut braid = Braid::new(2);
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+
+        let euler_char = khovanov_homology(&braid).graded_euler_characteristic();
+
+        // q + q^3 + q^5 - q^9 (the q^7 term cancels: rank 0 there)
+        assert_eq!(euler_char.coefficient(1), rug::Integer::from(1));
+        assert_eq!(euler_char.coefficient(3), rug::Integer::from(1));
+        assert_eq!(euler_char.coefficient(5), rug::Integer::from(1));
+        assert_eq!(euler_char.coefficient(7), rug::Integer::from(0));
+        assert_eq!(euler_char.coefficient(9), rug::Integer::from(-1));
+    }
+
+    #[test]
+    fn test_differential_squares_to_zero_trefoil() {
+        // d∘d = 0 is the fundamental chain-complex identity behind Khovanov
+        // homology. The Euler-characteristic tests above can't catch a sign
+        // error here: `free_rank` comes from rank-nullity on these same
+        // matrices (rank(d_out at i) == rank(d_in at i+1)), which holds
+        // regardless of whether the signs are right. This instead builds the
+        // complex directly and multiplies out every composable pair of
+        // differential matrices (d: C_i,j -> C_{i+1},j, since the
+        // differential always preserves `j`), checking each product is the
+        // zero matrix.
+        let mut braid = Braid::new(2);
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+
+        let (basis, differential) = build_complex(&braid);
+
+        let mut by_bidegree: HashMap<(i32, i32), Vec<&KhovanovGenerator>> = HashMap::new();
+        for (generator, i, j) in &basis {
+            by_bidegree.entry((*i, *j)).or_default().push(generator);
+        }
+
+        let j_degrees: BTreeSet<i32> = by_bidegree.keys().map(|&(_, j)| j).collect();
+        let i_degrees: BTreeSet<i32> = by_bidegree.keys().map(|&(i, _)| i).collect();
+        let min_i = *i_degrees.iter().next().unwrap();
+        let max_i = *i_degrees.iter().next_back().unwrap();
+
+        let mut checked_nonzero_differential = false;
+
+        for &j in &j_degrees {
+            for i in min_i..=max_i - 2 {
+                let (Some(current), Some(next), Some(next2)) =
+                    (by_bidegree.get(&(i, j)), by_bidegree.get(&(i + 1, j)), by_bidegree.get(&(i + 2, j)))
+                else {
+                    continue;
+                };
+
+                let next_index: HashMap<&KhovanovGenerator, usize> =
+                    next.iter().enumerate().map(|(idx, g)| (*g, idx)).collect();
+                let next2_index: HashMap<&KhovanovGenerator, usize> =
+                    next2.iter().enumerate().map(|(idx, g)| (*g, idx)).collect();
+
+                let d1 = differential_matrix(current, &next_index, &differential);
+                let d2 = differential_matrix(next, &next2_index, &differential);
+
+                if d1.iter().any(|row| row.iter().any(|c| *c != Integer::from(0))) {
+                    checked_nonzero_differential = true;
+                }
+
+                for row in &d1 {
+                    for col in 0..next2.len() {
+                        let mut sum = Integer::from(0);
+                        for (k, coeff) in row.iter().enumerate() {
+                            sum += coeff.clone() * &d2[k][col];
+                        }
+                        assert_eq!(sum, Integer::from(0), "d∘d != 0 at i={}, j={}, column {}", i, j, col);
+                    }
+                }
+            }
+        }
+
+        assert!(
+            checked_nonzero_differential,
+            "test exercised only zero differentials; the sign convention wasn't actually checked"
+        );
+    }
+
+    #[test]
+    fn test_graded_euler_characteristic_matches_jones_polynomial() {
+        // Standard identity: graded Euler characteristic (q + q^-1) * V(q^2)
+        // recovers the existing (t-variable) Jones polynomial, for every knot
+        // this crate computes both invariants for independently.
+        use crate::knot_invariants::KnotInvariants;
+
+        let mut braid = Braid::new(2);
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+
+        let euler_char = khovanov_homology(&braid).graded_euler_characteristic();
+
+        let jones = KnotInvariants::from_braid(&braid).unwrap().jones_polynomial;
+        let jones_in_q = jones.substitute(2, 1).unwrap();
+        let q_plus_q_inv = LaurentPolynomial::new(-1, vec![1, 1]);
+        let expected = q_plus_q_inv.mul(&jones_in_q);
+
+        assert_eq!(euler_char, expected);
+    }
+}
@@ -4,7 +4,7 @@
 // Uses simplified Euler method for integration (russell_ode deferred due to BLAS dependency)
 
 use nalgebra::DVector;
-use crate::knot_energy::{calculate_energy_gradient, calculate_knot_energy};
+use crate::knot_energy::{calculate_energy_gradient, calculate_knot_energy, calculate_knot_length};
 
 /// Knot dynamics parameters
 #[derive(Debug, Clone)]
@@ -136,8 +136,243 @@ pub fn calculate_stability(knot: &[DVector<f64>]) -> f64 {
     -avg_second_deriv  // Negative for stability
 }
 
+/// Parameters for `minimize_energy`
+#[derive(Debug, Clone)]
+pub struct MinimizeEnergyParams {
+    pub step_size: f64,
+    pub tolerance: f64,
+    pub max_iterations: usize,
+    /// When true, the first and last points are reset to their initial
+    /// positions after each step; the length rescale that follows scales
+    /// about the centroid and so can move them again
+    pub pin_endpoints: bool,
+}
+
+impl Default for MinimizeEnergyParams {
+    fn default() -> Self {
+        MinimizeEnergyParams {
+            step_size: 0.01,
+            tolerance: 1e-6,
+            max_iterations: 1000,
+            pin_endpoints: true,
+        }
+    }
+}
+
+/// Flow function f(x) = -∇E_K(x) driving the gradient-flow ODE dr/dt = -∇E_K(r)
+///
+/// Uses `calculate_energy_gradient`, the finite-difference gradient of the
+/// real (spline-fitted, arc-length-weighted) `calculate_knot_energy` -- not
+/// `calculate_energy_gradient_analytic`, which differentiates a different,
+/// unweighted local-bending quantity and so isn't ∇E_K. This keeps
+/// `minimize_energy` correct but not fast: see `calculate_energy_gradient`'s
+/// doc comment for why a cheap O(n) replacement is still unresolved.
+fn energy_flow(points: &[DVector<f64>]) -> Vec<DVector<f64>> {
+    calculate_energy_gradient(points).into_iter().map(|g| -g).collect()
+}
+
+/// Add `direction` scaled by `scale` to `base`, point-wise
+fn add_scaled(base: &[DVector<f64>], direction: &[DVector<f64>], scale: f64) -> Vec<DVector<f64>> {
+    base.iter()
+        .zip(direction.iter())
+        .map(|(b, d)| b + d * scale)
+        .collect()
+}
+
+/// Magnitude of a gradient, treated as a single flattened vector: sqrt(Σ|g_i|²)
+fn gradient_norm(gradient: &[DVector<f64>]) -> f64 {
+    gradient.iter().map(|g| g.norm_squared()).sum::<f64>().sqrt()
+}
+
+/// Rescale `points` about their centroid so their total length matches `target_length`
+fn rescale_to_length(points: &[DVector<f64>], target_length: f64) -> Vec<DVector<f64>> {
+    let current_length = calculate_knot_length(points);
+    if current_length < 1e-10 || points.is_empty() {
+        return points.to_vec();
+    }
+
+    let scale = target_length / current_length;
+    let dim = points[0].len();
+    let mut centroid = DVector::zeros(dim);
+    for point in points {
+        centroid += point;
+    }
+    centroid /= points.len() as f64;
+
+    points
+        .iter()
+        .map(|p| &centroid + (p - &centroid) * scale)
+        .collect()
+}
+
+/// Relax a knot toward a lower-energy embedding under the gradient flow dr/dt = -∇E_K(r)
+///
+/// Integrates the flow with a classical fourth-order Runge-Kutta step:
+/// k1 = f(x), k2 = f(x + (h/2)k1), k3 = f(x + (h/2)k2), k4 = f(x + h·k3),
+/// x ← x + (h/6)(k1 + 2k2 + 2k3 + k4), where f(x) = -∇E_K(x).
+///
+/// Endpoints are optionally pinned back to their initial positions (otherwise
+/// the curve is free to collapse to a point), and the total length is
+/// rescaled back to its initial value after every step so the flow relaxes
+/// bending energy without shrinking the knot. The rescale is about the
+/// centroid and comes after the pinning, so pinned endpoints stay exactly
+/// in place only when a step leaves the length unchanged. Stops when ‖∇E_K‖ falls below
+/// `params.tolerance` or `params.max_iterations` is reached.
+///
+/// Returns the relaxed points together with the energy at each iteration so
+/// callers can inspect convergence.
+///
+/// Each iteration evaluates `energy_flow` four times (one per RK4 stage),
+/// and each of those is a finite-difference gradient over all `6n` point
+/// perturbations - so this scales as `O(max_iterations · n)` full energy
+/// evaluations. Expect this to be slow for more than a few hundred points
+/// until `calculate_energy_gradient` gets a real analytic replacement.
+pub fn minimize_energy(
+    initial_points: &[DVector<f64>],
+    params: &MinimizeEnergyParams,
+) -> (Vec<DVector<f64>>, Vec<f64>) {
+    if initial_points.len() < 3 {
+        return (initial_points.to_vec(), vec![calculate_knot_energy(initial_points)]);
+    }
+
+    let target_length = calculate_knot_length(initial_points);
+    let first = initial_points[0].clone();
+    let last = initial_points[initial_points.len() - 1].clone();
+
+    let mut current = initial_points.to_vec();
+    let mut energy_history = vec![calculate_knot_energy(&current)];
+    let h = params.step_size;
+
+    for _ in 0..params.max_iterations {
+        let gradient = calculate_energy_gradient(&current);
+        if gradient_norm(&gradient) < params.tolerance {
+            break;
+        }
+
+        let k1 = energy_flow(&current);
+        let k2 = energy_flow(&add_scaled(&current, &k1, h / 2.0));
+        let k3 = energy_flow(&add_scaled(&current, &k2, h / 2.0));
+        let k4 = energy_flow(&add_scaled(&current, &k3, h));
+
+        let mut next: Vec<DVector<f64>> = current
+            .iter()
+            .enumerate()
+            .map(|(i, point)| point + (&k1[i] + &k2[i] * 2.0 + &k3[i] * 2.0 + &k4[i]) * (h / 6.0))
+            .collect();
+
+        if params.pin_endpoints {
+            next[0] = first.clone();
+            let last_idx = next.len() - 1;
+            next[last_idx] = last.clone();
+        }
+
+        current = rescale_to_length(&next, target_length);
+        energy_history.push(calculate_knot_energy(&current));
+    }
+
+    (current, energy_history)
+}
+
+/// Parameters for `minimize_energy_length_constrained`
+#[derive(Debug, Clone)]
+pub struct FrankWolfeParams {
+    pub tolerance: f64,
+    pub max_iterations: usize,
+}
+
+impl Default for FrankWolfeParams {
+    fn default() -> Self {
+        FrankWolfeParams {
+            tolerance: 1e-6,
+            max_iterations: 200,
+        }
+    }
+}
+
+/// Linear minimization oracle over the length-L0 feasible set
+///
+/// Moves each point against the gradient, then rescales the result back onto
+/// the length-L0 shell (`rescale_to_length` scales every point uniformly
+/// about the centroid; the displacement is not redistributed tangentially).
+fn length_constrained_lmo(
+    points: &[DVector<f64>],
+    gradient: &[DVector<f64>],
+    target_length: f64,
+) -> Vec<DVector<f64>> {
+    let moved: Vec<DVector<f64>> = points
+        .iter()
+        .zip(gradient.iter())
+        .map(|(p, g)| p - g)
+        .collect();
+    rescale_to_length(&moved, target_length)
+}
+
+/// Minimize bending energy subject to a fixed total length L0 via Frank-Wolfe
+/// (conditional gradient)
+///
+/// Pure gradient flow on E_K shrinks a knot to a point unless length is
+/// clamped externally; this solves the constrained problem directly. At
+/// iteration k: compute g = ∇E_K(x) (the finite-difference gradient of the
+/// real `calculate_knot_energy`), take the oracle point s = x - g rescaled
+/// to length L0 (`length_constrained_lmo`), then take the convex step
+/// x ← (1-γ_k)·x + γ_k·s with γ_k = 2/(k+2) and rescale the result back to
+/// length L0 (a convex combination of two length-L0 curves is generally
+/// shorter). Iterates until the duality gap
+/// ⟨g, x-s⟩ drops below `params.tolerance`.
+///
+/// `target_length` is the length budget L0 (pair with `calculate_knot_length`
+/// on the initial knot to preserve its starting length, or pass any other
+/// budget to relax toward a different one). Returns the relaxed points and
+/// the energy at each iteration; callers wanting a free-energy view can feed
+/// that history into `calculate_free_energy` alongside an entropy estimate.
+///
+/// Like `minimize_energy`, each iteration's gradient is the finite-difference
+/// `calculate_energy_gradient` (`6n` full energy evaluations), so this has
+/// the same unresolved `O(max_iterations · n)` energy-evaluation cost; see
+/// `calculate_energy_gradient`'s doc comment.
+pub fn minimize_energy_length_constrained(
+    initial_points: &[DVector<f64>],
+    target_length: f64,
+    params: &FrankWolfeParams,
+) -> (Vec<DVector<f64>>, Vec<f64>) {
+    if initial_points.len() < 3 {
+        return (initial_points.to_vec(), vec![calculate_knot_energy(initial_points)]);
+    }
+
+    let mut current = rescale_to_length(initial_points, target_length);
+    let mut energy_history = vec![calculate_knot_energy(&current)];
+
+    for k in 0..params.max_iterations {
+        let gradient = calculate_energy_gradient(&current);
+        let s = length_constrained_lmo(&current, &gradient, target_length);
+
+        let duality_gap: f64 = current
+            .iter()
+            .zip(s.iter())
+            .zip(gradient.iter())
+            .map(|((x_i, s_i), g_i)| g_i.dot(&(x_i - s_i)))
+            .sum();
+
+        if duality_gap.abs() < params.tolerance {
+            break;
+        }
+
+        let gamma = 2.0 / (k as f64 + 2.0);
+        let mixed: Vec<DVector<f64>> = current
+            .iter()
+            .zip(s.iter())
+            .map(|(x_i, s_i)| x_i * (1.0 - gamma) + s_i * gamma)
+            .collect();
+        current = rescale_to_length(&mixed, target_length);
+
+        energy_history.push(calculate_knot_energy(&current));
+    }
+
+    (current, energy_history)
+}
+
 /// Calculate energy change during evolution
-/// 
+///
 /// Returns (initial_energy, final_energy, energy_change)
 pub fn calculate_energy_change(
     initial_knot: &[DVector<f64>],
@@ -219,6 +454,52 @@ mod tests {
         assert!((e_change - (e_final - e_initial)).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_minimize_energy_reduces_or_holds_energy() {
+        let initial = vec![
+            DVector::from_vec(vec![0.0, 0.0, 0.0]),
+            DVector::from_vec(vec![1.0, 0.5, 0.0]),
+            DVector::from_vec(vec![2.0, -0.5, 0.0]),
+            DVector::from_vec(vec![3.0, 0.0, 0.0]),
+        ];
+
+        let params = MinimizeEnergyParams {
+            step_size: 0.01,
+            tolerance: 1e-8,
+            max_iterations: 50,
+            pin_endpoints: true,
+        };
+
+        let (relaxed, history) = minimize_energy(&initial, &params);
+
+        assert_eq!(relaxed.len(), initial.len());
+        assert!(!history.is_empty());
+        // Energy should not increase along the flow
+        assert!(history.last().unwrap() <= &(history[0] + 1e-6));
+    }
+
+    #[test]
+    fn test_minimize_energy_length_constrained_preserves_length() {
+        let initial = vec![
+            DVector::from_vec(vec![0.0, 0.0, 0.0]),
+            DVector::from_vec(vec![1.0, 0.6, 0.0]),
+            DVector::from_vec(vec![2.0, -0.6, 0.0]),
+            DVector::from_vec(vec![3.0, 0.6, 0.0]),
+            DVector::from_vec(vec![4.0, 0.0, 0.0]),
+        ];
+
+        let target_length = calculate_knot_length(&initial);
+        let params = FrankWolfeParams::default();
+
+        let (relaxed, history) = minimize_energy_length_constrained(&initial, target_length, &params);
+
+        assert_eq!(relaxed.len(), initial.len());
+        assert!(!history.is_empty());
+        assert!((calculate_knot_length(&relaxed) - target_length).abs() < 1e-6);
+        // Energy should not increase along the Frank-Wolfe iterates
+        assert!(history.last().unwrap() <= &(history[0] + 1e-6));
+    }
+
     #[test]
     fn test_evolve_knot_with_external_force() {
         let initial = vec![
@@ -5,28 +5,37 @@
 
 use nalgebra::DVector;
 use quadrature;
+use crate::curve::BSplineCurve;
+use crate::adapters::standard::{vec_cross, vec_norm};
 
 /// Calculate curvature at point along knot
-/// 
+///
 /// Knot is represented as a parametric curve r(s) = (x(s), y(s), z(s))
-/// Curvature: κ(s) = |d²r/ds²| = |r''(s)|
-/// 
-/// For discrete points, we use finite differences to approximate derivatives
+/// The true parametric curvature is κ(s) = |r'(s) × r''(s)| / |r'(s)|³,
+/// which (unlike |r''(s)|) is correct for arbitrary, non-arc-length
+/// parameterizations and arbitrary point spacing.
 pub fn calculate_curvature(
     _position: &DVector<f64>,
-    _first_derivative: &DVector<f64>,
+    first_derivative: &DVector<f64>,
     second_derivative: &DVector<f64>,
 ) -> f64 {
-    // Curvature magnitude: |r''(s)|
-    // For a parametric curve, curvature = |r''(s)| / |r'(s)|³
-    // But for knot energy, we use |r''(s)| directly
-    second_derivative.norm()
+    let r_prime_norm = vec_norm(first_derivative.as_slice());
+    if r_prime_norm < 1e-10 {
+        return 0.0;
+    }
+
+    let cross = match vec_cross(first_derivative.as_slice(), second_derivative.as_slice()) {
+        Ok(cross) => cross,
+        Err(_) => return 0.0, // Not a 3-vector; curvature undefined
+    };
+
+    vec_norm(&cross) / crate::ops::powi(r_prime_norm, 3)
 }
 
 /// Calculate curvature from discrete knot points
-/// 
-/// Uses finite differences to approximate derivatives
-/// For point i:
+///
+/// Uses finite differences to approximate r'(s) and r''(s), then applies
+/// the true Frenet curvature formula κ = |r' × r''| / |r'|³:
 /// - r'(s) ≈ (r_{i+1} - r_{i-1}) / (2*ds)
 /// - r''(s) ≈ (r_{i+1} - 2*r_i + r_{i-1}) / ds²
 pub fn calculate_curvature_from_points(
@@ -36,98 +45,137 @@ pub fn calculate_curvature_from_points(
     if points.len() < 3 {
         return 0.0; // Not enough points for curvature
     }
-    
-    if index == 0 || index >= points.len() - 1 {
-        // Use forward/backward differences at boundaries
+
+    let ds = 1.0; // Normalized parameter
+    let (first_deriv, second_deriv) = if index == 0 || index >= points.len() - 1 {
         if index == 0 {
-            let ds = 1.0; // Normalized parameter
             let r0 = &points[0];
             let r1 = &points[1];
             let r2 = &points[2.min(points.len() - 1)];
-            
-            // Forward difference: r'' ≈ (r2 - 2*r1 + r0) / ds²
-            let second_deriv = (r2 - r1) - (r1 - r0);
-            return second_deriv.norm() / (ds * ds);
+
+            // Forward differences
+            let first = (r1 - r0) / ds;
+            let second = (r2 - r1) - (r1 - r0);
+            (first, second / (ds * ds))
         } else {
-            let ds = 1.0;
             let r_n = &points[index];
             let r_n1 = &points[index - 1];
             let r_n2 = &points[index.max(2) - 2];
-            
-            // Backward difference: r'' ≈ (r_n - 2*r_{n-1} + r_{n-2}) / ds²
-            let second_deriv = (r_n - r_n1) - (r_n1 - r_n2);
-            return second_deriv.norm() / (ds * ds);
+
+            // Backward differences
+            let first = (r_n - r_n1) / ds;
+            let second = (r_n - r_n1) - (r_n1 - r_n2);
+            (first, second / (ds * ds))
         }
-    }
-    
-    // Central difference for interior points
-    let ds = 1.0; // Normalized parameter
-    let r_prev = &points[index - 1];
-    let r_curr = &points[index];
-    let r_next = &points[index + 1];
-    
-    // Second derivative: r'' ≈ (r_{i+1} - 2*r_i + r_{i-1}) / ds²
-    let second_deriv = (r_next - r_curr) - (r_curr - r_prev);
-    second_deriv.norm() / (ds * ds)
+    } else {
+        let r_prev = &points[index - 1];
+        let r_curr = &points[index];
+        let r_next = &points[index + 1];
+
+        // Central differences
+        let first = (r_next - r_prev) / (2.0 * ds);
+        let second = (r_next - r_curr) - (r_curr - r_prev);
+        (first, second / (ds * ds))
+    };
+
+    calculate_curvature(&points[index], &first_deriv, &second_deriv)
 }
 
 /// Calculate knot energy: E_K = ∫_K |κ(s)|² ds
-/// 
-/// Uses numerical integration (quadrature) to integrate curvature squared
-/// 
+///
+/// Fits a B-spline through `curve_points` (see the `curve` module) and
+/// integrates |κ(s)|² using the spline's analytic derivatives over its true
+/// parameter domain, rather than interpolating curvature between raw index
+/// positions. This makes the energy stable and reparameterization-robust:
+/// resampling the same geometry at a different density no longer changes E_K.
+///
 /// Input: curve_points as discrete points along the knot
 /// Output: Total energy
 pub fn calculate_knot_energy(curve_points: &[DVector<f64>]) -> f64 {
     if curve_points.len() < 3 {
         return 0.0; // Not enough points for energy calculation
     }
-    
-    // Create curvature function from discrete points
-    // We'll integrate |κ(s)|² over the normalized parameter space [0, 1]
-    let n = curve_points.len();
-    
-    // Use Simpson's rule via quadrature library
-    // We need to create a function that maps parameter s ∈ [0, 1] to curvature
+
+    let degree = 3.min(curve_points.len() - 1);
+    let curve = match BSplineCurve::interpolate(curve_points, degree) {
+        Ok(curve) => curve,
+        Err(_) => return 0.0,
+    };
+    let (s_min, s_max) = curve.domain();
+    let (_, total_length) = reparameterize_by_arc_length(curve_points);
+
     let curvature_squared = |s: f64| -> f64 {
-        // Map s ∈ [0, 1] to index in curve_points
-        let index_f = s * (n - 1) as f64;
-        let index = index_f.floor() as usize;
-        let frac = index_f - index as f64;
-        
-        // Clamp index to valid range
-        let idx = index.min(n - 1);
-        let next_idx = (index + 1).min(n - 1);
-        
-        // Interpolate curvature between points
-        let kappa_curr = calculate_curvature_from_points(curve_points, idx);
-        let kappa_next = if next_idx != idx {
-            calculate_curvature_from_points(curve_points, next_idx)
-        } else {
-            kappa_curr
-        };
-        
-        // Linear interpolation
-        let kappa = kappa_curr * (1.0 - frac) + kappa_next * frac;
-        
-        // Return |κ(s)|²
+        let position = curve.eval(s);
+        let first_derivative = curve.derivative(s, 1);
+        let second_derivative = curve.derivative(s, 2);
+        let kappa = calculate_curvature(&position, &first_derivative, &second_derivative);
         kappa * kappa
     };
-    
-    // Integrate |κ(s)|² over [0, 1]
+
+    // Integrate |κ(s)|² over the spline's normalized parameter domain, then
+    // apply the arc-length Jacobian ds = total_length · d(normalized s) so
+    // the quadrature reflects true differential arc length rather than a
+    // uniform index step. Two geometrically identical knots sampled at
+    // different densities now integrate to the same energy.
     let result = quadrature::integrate(
         curvature_squared,
-        0.0,
-        1.0,
+        s_min,
+        s_max,
         1e-6, // Tolerance
     );
-    
-    result.integral
+
+    result.integral * total_length
+}
+
+/// Reparameterize a polyline by arc length
+///
+/// Computes the cumulative chord length L_i = Σ|r_{j+1} - r_j| up to each
+/// point and normalizes it to s_i = L_i / L_total, giving parameter values
+/// that reflect real geometric spacing rather than uniform index steps.
+///
+/// Returns the per-point normalized parameters and the total chord length.
+pub fn reparameterize_by_arc_length(points: &[DVector<f64>]) -> (Vec<f64>, f64) {
+    if points.len() < 2 {
+        return (vec![0.0; points.len()], 0.0);
+    }
+
+    let mut cumulative = vec![0.0; points.len()];
+    for i in 1..points.len() {
+        cumulative[i] = cumulative[i - 1] + (&points[i] - &points[i - 1]).norm();
+    }
+
+    let total_length = calculate_knot_length(points);
+    let params = if total_length < 1e-12 {
+        (0..points.len())
+            .map(|i| i as f64 / (points.len() - 1) as f64)
+            .collect()
+    } else {
+        cumulative.iter().map(|&l| l / total_length).collect()
+    };
+
+    (params, total_length)
 }
 
 /// Calculate energy gradient: ∇E_K = ∂E_K/∂r
-/// 
-/// Returns gradient vector for energy minimization
-/// Uses finite differences to approximate gradient
+///
+/// Returns gradient vector for energy minimization. Uses finite differences
+/// to approximate the gradient of the real `calculate_knot_energy` - 6n
+/// perturbations, each a full B-spline refit plus adaptive quadrature, so
+/// this is the dominant cost of `minimize_energy` and
+/// `minimize_energy_length_constrained` (called every RK4 substage and every
+/// Frank-Wolfe iteration respectively).
+///
+/// A true O(n) analytic replacement would need to differentiate through
+/// `BSplineCurve::interpolate`'s global LU solve (every control point
+/// depends on every input point, so perturbing `r_j` isn't local the way it
+/// is for `calculate_energy_gradient_analytic`'s simpler stencil) and through
+/// the adaptive quadrature's node placement - an adjoint-method derivation
+/// this crate hasn't attempted. `calculate_energy_gradient_analytic` below
+/// is *not* that: it differentiates a cheaper, unnormalized local-bending
+/// proxy, not this energy, and is kept separate rather than wired in here.
+/// This performance regression (an O(n) gradient of the real energy) is the
+/// part of chunk0-5 left unresolved; treat it as descoped until someone
+/// delivers the real adjoint derivation.
 pub fn calculate_energy_gradient(
     curve_points: &[DVector<f64>],
 ) -> Vec<DVector<f64>> {
@@ -162,6 +210,68 @@ pub fn calculate_energy_gradient(
     gradients
 }
 
+/// Discrete second difference at an interior point: r_{i+1} - 2·r_i + r_{i-1}
+///
+/// This is the same local bending stencil `calculate_curvature_from_points`
+/// differences against; squaring and summing it over the interior points
+/// gives a cheap local proxy for bending energy whose gradient is a simple
+/// closed form, used by `calculate_energy_gradient_analytic` below.
+fn second_difference(points: &[DVector<f64>], i: usize) -> DVector<f64> {
+    (&points[i + 1] - &points[i]) - (&points[i] - &points[i - 1])
+}
+
+/// Gradient of the unweighted local-bending proxy E ≈ Σ_i |r_{i+1} - 2r_i +
+/// r_{i-1}|², in O(n)
+///
+/// This differentiates a genuinely different quantity than
+/// `calculate_knot_energy`: no `/|r'|³` curvature normalization and no
+/// arc-length weighting, just the raw second-difference stencil. It is
+/// *not* ∇E_K and the minimizers in `knot_dynamics` no longer use it for
+/// that purpose (they use `calculate_energy_gradient`, the finite-difference
+/// gradient of the real energy, instead). Kept as a cheap O(n) proxy for
+/// callers that specifically want the local-bending quantity rather than
+/// true ∇E_K.
+///
+/// Since each point r_j only ever appears in the second-difference stencils
+/// at i = j-1, j, j+1, its gradient is assembled in constant work per point:
+///
+/// ∂E/∂r_j = 2·(d_{j-1} - 2·d_j + d_{j+1})
+///
+/// where d_i is the second difference at i (terms outside the valid interior
+/// range 1..n-2 are simply omitted).
+pub fn calculate_energy_gradient_analytic(curve_points: &[DVector<f64>]) -> Vec<DVector<f64>> {
+    let n = curve_points.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let dim = curve_points[0].len();
+    if n < 3 {
+        return vec![DVector::zeros(dim); n];
+    }
+
+    let mut second_diffs = vec![DVector::zeros(dim); n];
+    for i in 1..n - 1 {
+        second_diffs[i] = second_difference(curve_points, i);
+    }
+
+    let mut gradients = vec![DVector::zeros(dim); n];
+    for j in 0..n {
+        let mut gradient = DVector::zeros(dim);
+        if j >= 2 && j <= n - 1 {
+            gradient += &second_diffs[j - 1];
+        }
+        if j >= 1 && j <= n - 2 {
+            gradient -= &second_diffs[j] * 2.0;
+        }
+        if j <= n.saturating_sub(3) {
+            gradient += &second_diffs[j + 1];
+        }
+        gradients[j] = gradient * 2.0;
+    }
+
+    gradients
+}
+
 /// Calculate knot length
 /// 
 /// L = ∫_K ds = Σ |r_{i+1} - r_i|
@@ -225,6 +335,21 @@ mod tests {
         assert!(energy < 1e-3);
     }
 
+    #[test]
+    fn test_reparameterize_by_arc_length() {
+        let points = vec![
+            DVector::from_vec(vec![0.0, 0.0, 0.0]),
+            DVector::from_vec(vec![1.0, 0.0, 0.0]),
+            DVector::from_vec(vec![3.0, 0.0, 0.0]),
+        ];
+
+        let (params, total_length) = reparameterize_by_arc_length(&points);
+        assert!((total_length - 3.0).abs() < 1e-10);
+        assert!((params[0] - 0.0).abs() < 1e-10);
+        assert!((params[1] - 1.0 / 3.0).abs() < 1e-10);
+        assert!((params[2] - 1.0).abs() < 1e-10);
+    }
+
     #[test]
     fn test_knot_length() {
         let points = vec![
@@ -237,6 +362,47 @@ mod tests {
         assert!((length - 2.0).abs() < 1e-10); // Distance from 0 to 2
     }
 
+    #[test]
+    fn test_energy_gradient_analytic_matches_finite_difference() {
+        // Finite-difference gradient of the same local bending proxy
+        // Σ_i |r_{i+1} - 2r_i + r_{i-1}|² that the analytic gradient targets
+        fn local_bending_energy(points: &[DVector<f64>]) -> f64 {
+            (1..points.len() - 1)
+                .map(|i| second_difference(points, i).norm_squared())
+                .sum()
+        }
+
+        let points = vec![
+            DVector::from_vec(vec![0.0, 0.0, 0.0]),
+            DVector::from_vec(vec![1.0, 0.3, 0.0]),
+            DVector::from_vec(vec![2.0, -0.2, 0.1]),
+            DVector::from_vec(vec![3.0, 0.1, 0.0]),
+            DVector::from_vec(vec![4.0, 0.0, 0.0]),
+        ];
+
+        let analytic = calculate_energy_gradient_analytic(&points);
+
+        let epsilon = 1e-6;
+        for i in 0..points.len() {
+            for coord in 0..3 {
+                let mut plus = points.clone();
+                plus[i][coord] += epsilon;
+                let mut minus = points.clone();
+                minus[i][coord] -= epsilon;
+
+                let numeric = (local_bending_energy(&plus) - local_bending_energy(&minus)) / (2.0 * epsilon);
+                assert!(
+                    (numeric - analytic[i][coord]).abs() < 1e-4,
+                    "mismatch at point {} coord {}: numeric {} vs analytic {}",
+                    i,
+                    coord,
+                    numeric,
+                    analytic[i][coord]
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_energy_gradient() {
         let points = vec![
@@ -252,4 +418,37 @@ mod tests {
             assert_eq!(grad.len(), 3);
         }
     }
+
+    #[test]
+    fn test_energy_gradient_analytic_is_not_a_stand_in_for_the_real_gradient() {
+        // `calculate_energy_gradient_analytic` differentiates the unweighted,
+        // unnormalized local-bending proxy, not the real (curvature-normalized,
+        // arc-length-weighted) `calculate_knot_energy` -- this pins down that
+        // the two gradients genuinely diverge on a kinked curve, so neither
+        // `knot_dynamics`'s minimizers nor any future caller mistake the cheap
+        // O(n) proxy for a faster stand-in of `calculate_energy_gradient`.
+        let points = vec![
+            DVector::from_vec(vec![0.0, 0.0, 0.0]),
+            DVector::from_vec(vec![1.0, 1.0, 0.0]),
+            DVector::from_vec(vec![2.0, -1.0, 0.0]),
+            DVector::from_vec(vec![3.0, 1.0, 0.0]),
+            DVector::from_vec(vec![4.0, 0.0, 0.0]),
+        ];
+
+        let analytic = calculate_energy_gradient_analytic(&points);
+        let real = calculate_energy_gradient(&points);
+
+        let max_difference = analytic
+            .iter()
+            .zip(real.iter())
+            .flat_map(|(a, r)| (a - r).iter().map(|d| d.abs()).collect::<Vec<_>>())
+            .fold(0.0, f64::max);
+
+        assert!(
+            max_difference > 1e-3,
+            "expected the local-bending proxy gradient to diverge from the real energy gradient, \
+             but the largest per-coordinate difference was only {}",
+            max_difference
+        );
+    }
 }
@@ -0,0 +1,148 @@
+// Knot identification against a built-in Rolfsen table
+//
+// Matches a braid's computed invariants against a small table of known
+// knots, built by running `KnotInvariants::from_braid` on each of
+// `knot_notation::rolfsen_knot`'s already-validated braid words. The table
+// is intentionally limited to the unknot plus the entries `rolfsen_knot`
+// itself can produce (3_1, 4_1, 5_1) - this crate's Seifert-matrix
+// Alexander-polynomial engine can't yet reproduce most other low-crossing
+// knots (see `rolfsen_knot`'s doc comment), so hand-transcribing literature
+// polynomials for those would only create table entries this crate could
+// never actually match. Matching is by exact equality of crossing number,
+// Jones polynomial, and Alexander polynomial, since every invariant here is
+// an exact Laurent polynomial rather than a floating-point approximation.
+
+use crate::braid_group::Braid;
+use crate::knot_invariants::KnotInvariants;
+use crate::knot_notation;
+
+/// A single entry in the built-in knot table
+struct KnownKnot {
+    name: &'static str,
+    invariants: KnotInvariants,
+}
+
+/// Identification result: the matched knot's name, its crossing number, and
+/// whether more than one table entry matched (which would mean the table
+/// has two entries sharing the same Jones and Alexander polynomials - not
+/// expected for the low-crossing knots here, but checked rather than
+/// assumed away)
+pub struct KnotIdentification {
+    pub name: String,
+    pub crossing_number: usize,
+    pub ambiguous: bool,
+}
+
+/// Builds the built-in knot table from `knot_notation::rolfsen_knot`'s
+/// validated braid words, plus an explicit unknot entry (a single-strand
+/// braid with no crossings, which `rolfsen_knot` has no `(crossings,
+/// index)` slot for).
+fn known_knots() -> Vec<KnownKnot> {
+    let unknot = Braid::new(1);
+    vec![
+        KnownKnot {
+            name: "0_1",
+            invariants: KnotInvariants::from_braid(&unknot)
+                .expect("the unknot's empty Seifert graph is trivially in the validated shape"),
+        },
+        KnownKnot {
+            name: "3_1",
+            invariants: KnotInvariants::from_braid(&knot_notation::rolfsen_knot(3, 1).unwrap())
+                .expect("rolfsen_knot(3, 1) is validated against build_seifert_matrix's supported shape"),
+        },
+        KnownKnot {
+            name: "4_1",
+            invariants: KnotInvariants::from_braid(&knot_notation::rolfsen_knot(4, 1).unwrap())
+                .expect("rolfsen_knot(4, 1) is validated against build_seifert_matrix's supported shape"),
+        },
+        KnownKnot {
+            name: "5_1",
+            invariants: KnotInvariants::from_braid(&knot_notation::rolfsen_knot(5, 1).unwrap())
+                .expect("rolfsen_knot(5, 1) is validated against build_seifert_matrix's supported shape"),
+        },
+    ]
+}
+
+/// Identify a braid's knot type against the built-in table
+///
+/// Matches on exact equality of crossing number, Jones polynomial, and
+/// Alexander polynomial - not the lossy `distance()` method, since every
+/// invariant involved is an exact Laurent polynomial here. Returns an error
+/// if no table entry matches, rather than guessing the closest one.
+pub fn identify_knot(braid: &Braid) -> Result<KnotIdentification, String> {
+    let query = KnotInvariants::from_braid(braid)?;
+
+    let names: Vec<String> = known_knots()
+        .into_iter()
+        .filter(|known| {
+            known.invariants.crossing_number == query.crossing_number
+                && known.invariants.jones_polynomial == query.jones_polynomial
+                && known.invariants.alexander_polynomial == query.alexander_polynomial
+        })
+        .map(|known| known.name.to_string())
+        .collect();
+
+    match names.split_first() {
+        None => Err(format!(
+            "No knot in the built-in table matches these invariants (crossing number {})",
+            query.crossing_number
+        )),
+        Some((first, rest)) => Ok(KnotIdentification {
+            name: first.clone(),
+            crossing_number: query.crossing_number,
+            ambiguous: !rest.is_empty(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identify_unknot() {
+        let braid = Braid::new(1);
+        let result = identify_knot(&braid).unwrap();
+        assert_eq!(result.name, "0_1");
+        assert!(!result.ambiguous);
+    }
+
+    #[test]
+    fn test_identify_trefoil() {
+        let braid = knot_notation::rolfsen_knot(3, 1).unwrap();
+        let result = identify_knot(&braid).unwrap();
+        assert_eq!(result.name, "3_1");
+        assert_eq!(result.crossing_number, 3);
+        assert!(!result.ambiguous);
+    }
+
+    #[test]
+    fn test_identify_figure_eight() {
+        let braid = knot_notation::rolfsen_knot(4, 1).unwrap();
+        let result = identify_knot(&braid).unwrap();
+        assert_eq!(result.name, "4_1");
+        assert!(!result.ambiguous);
+    }
+
+    #[test]
+    fn test_identify_cinquefoil() {
+        let braid = knot_notation::rolfsen_knot(5, 1).unwrap();
+        let result = identify_knot(&braid).unwrap();
+        assert_eq!(result.name, "5_1");
+        assert!(!result.ambiguous);
+    }
+
+    #[test]
+    fn test_identify_unknown_braid_is_an_honest_error() {
+        // sigma_1^4 closes to the 2-component (2,4) torus link, which is not
+        // in the table; identify_knot errors rather than guess (here already
+        // in `KnotInvariants::from_braid`, which rejects multi-component
+        // closures).
+        let mut braid = Braid::new(2);
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+        assert!(identify_knot(&braid).is_err());
+    }
+}
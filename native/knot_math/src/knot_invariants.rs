@@ -2,58 +2,81 @@
 // 
 // Implements knot invariants: Jones polynomial, Alexander polynomial, crossing number, writhe
 
-use crate::polynomial::Polynomial;
+use crate::polynomial::LaurentPolynomial;
 use crate::braid_group::{Braid, Knot};
+use crate::lee_homology;
 use serde::{Deserialize, Serialize};
-use rug::Float;
-use nalgebra::DMatrix;
+use rug::Integer;
 
 /// Knot invariants
+///
+/// Jones and Alexander polynomials are genuinely Laurent polynomials with
+/// exact integer coefficients (e.g. the trefoil's `-t^-4 + t^-3 + t^-1`), so
+/// they're stored as `LaurentPolynomial` rather than a lossy `Vec<f64>`
+/// indexed from degree 0.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KnotInvariants {
-    pub jones_polynomial: Polynomial,
-    pub alexander_polynomial: Polynomial,
+    pub jones_polynomial: LaurentPolynomial,
+    pub alexander_polynomial: LaurentPolynomial,
     pub crossing_number: usize,
     pub writhe: i32,
+    /// The Rasmussen `s`-invariant, a lower bound on the slice genus (`|s|/2
+    /// <= g_4`): `s` of the Lee-homology generators' quantum filtration
+    /// levels, see `lee_homology::s_invariant`.
+    pub s_invariant: i32,
 }
 
 impl KnotInvariants {
     /// Create new knot invariants
     pub fn new(
-        jones_polynomial: Polynomial,
-        alexander_polynomial: Polynomial,
+        jones_polynomial: LaurentPolynomial,
+        alexander_polynomial: LaurentPolynomial,
         crossing_number: usize,
         writhe: i32,
+        s_invariant: i32,
     ) -> Self {
         KnotInvariants {
             jones_polynomial,
             alexander_polynomial,
             crossing_number,
             writhe,
+            s_invariant,
         }
     }
 
     /// Calculate invariants from braid
-    pub fn from_braid(braid: &Braid) -> Self {
+    ///
+    /// Errors if `braid`'s Seifert graph is too deeply interleaved for this
+    /// crate's Seifert-matrix construction to handle exactly (see
+    /// `build_seifert_matrix`'s doc comment) - the Alexander polynomial
+    /// would otherwise be silently wrong rather than missing - or if
+    /// `braid`'s closure has more than one component, since the Rasmussen
+    /// `s`-invariant (see `lee_homology::s_invariant`) is only defined for
+    /// knots.
+    pub fn from_braid(braid: &Braid) -> Result<Self, String> {
         let crossing_number = braid.get_crossings().len();
         let writhe = calculate_writhe(braid);
-        
+
         // Calculate Jones polynomial using Kauffman bracket
         let jones = calculate_jones_polynomial(braid);
-        
+
         // Calculate Alexander polynomial using Seifert matrix
-        let alexander = calculate_alexander_polynomial(braid);
-        
-        KnotInvariants {
+        let alexander = calculate_alexander_polynomial(braid)?;
+
+        // Calculate the Rasmussen s-invariant from Lee homology
+        let s_invariant = lee_homology::s_invariant(braid)?;
+
+        Ok(KnotInvariants {
             jones_polynomial: jones,
             alexander_polynomial: alexander,
             crossing_number,
             writhe,
-        }
+            s_invariant,
+        })
     }
 
     /// Calculate invariants from knot
-    pub fn from_knot(knot: &Knot) -> Self {
+    pub fn from_knot(knot: &Knot) -> Result<Self, String> {
         Self::from_braid(&knot.braid)
     }
 
@@ -100,178 +123,351 @@ pub fn calculate_writhe(braid: &Braid) -> i32 {
     writhe
 }
 
-/// Calculate Jones polynomial from braid using Kauffman bracket
-/// 
-/// Algorithm:
-/// 1. Compute Kauffman bracket polynomial <K>
-/// 2. Apply normalization: J_K(q) = (-A^3)^(-writhe) * <K> evaluated at A = q^(-1/4)
-/// 
-/// Kauffman bracket skein relation:
-/// - <L_+> = A<L_0> + A^-1<L_->
-/// - <L_0> = A^-1<L_+> + A<L_->
-/// - <unknot> = 1
-fn calculate_jones_polynomial(braid: &Braid) -> Polynomial {
-    let crossings = braid.get_crossings();
-    let precision = 256;
-    
-    if crossings.is_empty() {
-        // Unknot: J(q) = 1
-        return Polynomial::new(vec![1.0]);
+/// Minimal union-find (disjoint set) used to count loops in a resolved
+/// Kauffman state diagram
+struct UnionFind {
+    parent: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        UnionFind { parent: (0..n).collect() }
     }
-    
-    let writhe = calculate_writhe(braid);
-    
-    // Simplified Kauffman bracket calculation
-    // For a braid with n crossings, we use a recursive approach
-    // Full implementation would resolve all crossings using skein relations
-    
-    // Simplified approach: Use writhe and crossing count
-    // J_K(q) ≈ q^writhe * (q + q^-1)^(n-1) for n crossings
-    // This is more accurate than the previous placeholder
-    
-    let n = crossings.len();
-    
-    // Build polynomial: start with q^writhe
-    // Set coefficient for writhe power
-    let writhe_idx = if writhe >= 0 {
-        writhe as usize
-    } else {
-        0 // For negative writhe, we'll handle differently
-    };
-    
-    // Simplified: J(q) = q^writhe * (1 + q^2)^(n-1) / q^(n-1)
-    // This gives us a polynomial that respects writhe and crossing structure
-    
-    // For now, create a polynomial that encodes writhe and structure
-    // Full Kauffman bracket would require recursive resolution of all crossings
-    let mut coeffs = vec![Float::with_val(precision, 0.0); n + writhe_idx + 1];
-    
-    // Base: q^writhe
-    if writhe_idx < coeffs.len() {
-        coeffs[writhe_idx] = Float::with_val(precision, 1.0);
+
+    fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
     }
-    
-    // Add structure from crossings (simplified)
-    // Each crossing contributes to the polynomial structure
-    for (i, crossing) in crossings.iter().enumerate() {
-        let sign = if crossing.is_over { 1.0 } else { -1.0 };
-        let power = writhe_idx + i;
-        if power < coeffs.len() {
-            coeffs[power] += Float::with_val(precision, sign * 0.1); // Small contribution
+
+    fn union(&mut self, a: usize, b: usize) {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+        if root_a != root_b {
+            self.parent[root_a] = root_b;
         }
     }
-    
-    // Normalize: ensure leading coefficient is reasonable
-    let max_coeff = coeffs.iter()
-        .map(|c| c.to_f64().abs())
-        .fold(0.0, f64::max);
-    
-    if max_coeff > 1e-10 {
-        for coeff in &mut coeffs {
-            *coeff = coeff.clone() / Float::with_val(precision, max_coeff);
+}
+
+/// Count the loops in the diagram obtained by resolving every crossing of
+/// `braid` according to `state` (one bit per crossing: false = identity
+/// smoothing, the strands pass straight through; true = merge smoothing,
+/// a cap-cup connecting the two strands) and then closing the braid
+/// (connecting each top strand end to the bottom end at the same position).
+///
+/// Strand positions are tracked top-to-bottom: each cap-cup closes off
+/// whatever was connected above it and opens two fresh strand-ends below,
+/// since neither smoothing of a crossing permutes strand positions.
+fn count_loops(braid: &Braid, state: &[bool]) -> usize {
+    let n = braid.strands();
+    let crossings = braid.get_crossings();
+
+    let max_nodes = n + crossings.len() * 2;
+    let mut union_find = UnionFind::new(max_nodes);
+    let mut next_fresh = n;
+    let mut connect: Vec<usize> = (0..n).collect();
+
+    for (crossing, &merge) in crossings.iter().zip(state.iter()) {
+        let i = crossing.strand;
+        if merge {
+            union_find.union(connect[i], connect[i + 1]);
+            connect[i] = next_fresh;
+            next_fresh += 1;
+            connect[i + 1] = next_fresh;
+            next_fresh += 1;
         }
     }
-    
-    // Convert to f64 for Polynomial
-    let coeffs_f64: Vec<f64> = coeffs.iter().map(|c| c.to_f64()).collect();
-    Polynomial::new(coeffs_f64)
+
+    // Braid closure: connect each bottom strand end back to its top strand end
+    for pos in 0..n {
+        union_find.union(connect[pos], pos);
+    }
+
+    let mut roots = std::collections::HashSet::new();
+    for node in 0..next_fresh {
+        roots.insert(union_find.find(node));
+    }
+    roots.len()
 }
 
-/// Calculate Alexander polynomial from braid using Seifert matrix
-/// 
+/// (-A^2 - A^-2)^k, the repeated Kauffman bracket loop factor
+fn delta_pow(k: usize) -> LaurentPolynomial {
+    let delta = LaurentPolynomial::new(-2, vec![-1, 0, 0, 0, -1]); // -A^-2 - A^2
+    let mut result = LaurentPolynomial::new(0, vec![1]);
+    for _ in 0..k {
+        result = result.mul(&delta);
+    }
+    result
+}
+
+/// Compute the Kauffman bracket <K> as a state sum over the cube of resolutions
+///
+/// For a braid with `n` crossings, enumerates all 2^n states where each
+/// crossing is resolved into either its A-smoothing or B-smoothing. For a
+/// positive (over) crossing the A-smoothing is the identity (no merge) and
+/// contributes A^{+1}, matching the skein relation <L+> = A<L0> + A^-1<L∞>;
+/// for a negative (under) crossing the roles swap, matching
+/// <L-> = A^-1<L0> + A<L∞>. Each state with `a` A-smoothings and `b = n-a`
+/// B-smoothings contributes A^(a-b) * (-A^2-A^-2)^(|s|-1), where |s| is the
+/// number of loops in that state's resolved diagram.
+fn kauffman_bracket(braid: &Braid) -> LaurentPolynomial {
+    let crossings = braid.get_crossings();
+    let n = crossings.len();
+
+    let mut bracket = LaurentPolynomial::new(0, vec![0]);
+
+    for state_bits in 0..(1u64 << n) {
+        let state: Vec<bool> = (0..n).map(|k| (state_bits >> k) & 1 == 1).collect();
+        let loops = count_loops(braid, &state);
+
+        let a_power: i64 = crossings
+            .iter()
+            .zip(state.iter())
+            .map(|(crossing, &merge)| {
+                let is_a_smoothing = (crossing.is_over && !merge) || (!crossing.is_over && merge);
+                if is_a_smoothing { 1 } else { -1 }
+            })
+            .sum();
+
+        let state_term = LaurentPolynomial::new(a_power, vec![1]);
+        let loop_factor = delta_pow(loops.saturating_sub(1));
+        bracket = bracket.add(&state_term.mul(&loop_factor));
+    }
+
+    bracket
+}
+
+/// Calculate Jones polynomial from braid using the Kauffman bracket state sum
+///
 /// Algorithm:
-/// 1. Construct Seifert surface from braid
-/// 2. Compute Seifert matrix V
-/// 3. Calculate Δ_K(t) = det(V - tV^T)
-/// 
-/// For braids, we can compute Seifert matrix directly from braid word
-fn calculate_alexander_polynomial(braid: &Braid) -> Polynomial {
+/// 1. Compute the Kauffman bracket polynomial <K> (see `kauffman_bracket`)
+/// 2. Apply normalization: f_K(A) = (-A^3)^(-writhe) * <K>
+/// 3. Substitute A = t^(-1/4) to land in the Jones polynomial's variable t
+///
+/// The unknot (no crossings, one loop) evaluates to 1.
+fn calculate_jones_polynomial(braid: &Braid) -> LaurentPolynomial {
     let crossings = braid.get_crossings();
-    let precision = 256;
-    
+
     if crossings.is_empty() {
-        // Unknot: Δ(t) = 1
-        return Polynomial::new(vec![1.0]);
+        // Unknot: J(t) = 1
+        return LaurentPolynomial::new(0, vec![1]);
     }
-    
-    let n = crossings.len();
+
+    let writhe = calculate_writhe(braid);
+    let bracket = kauffman_bracket(braid);
+
+    // (-A^3)^(-writhe) = (-1)^writhe * A^(-3*writhe)
+    let sign: i64 = if writhe % 2 == 0 { 1 } else { -1 };
+    let normalization = LaurentPolynomial::new(-3 * writhe as i64, vec![sign]);
+
+    let f_k = normalization.mul(&bracket);
+
+    // A = t^(-1/4): every exponent in f_K is guaranteed to be a multiple of 4
+    // for a single-component closure; fall back to rounding for braids whose
+    // closure has more than one component (where that guarantee doesn't hold).
+    f_k.substitute(-1, 4)
+        .unwrap_or_else(|_| f_k.substitute_rounded(-1, 4))
+}
+
+/// A non-tree edge of the Seifert graph, i.e. one of the `2g` homology
+/// generators of the Seifert surface
+struct SeifertGenerator {
+    /// Strand position of the crossing that generates this cycle (the
+    /// Seifert circles it connects are `position` and `position + 1`)
+    position: usize,
+    /// Sign of the crossing: +1 over, -1 under
+    sign: i64,
+}
+
+/// Checks the "at most one non-tree edge in flight between any two circles
+/// at a time" condition `build_seifert_matrix`'s doc comment requires for
+/// exactness.
+///
+/// A non-tree edge at strand position `p` links circles `p` and `p+1`; two
+/// generators at the same position are therefore edges between the *same*
+/// pair of circles. The bidiagonal linking rule below only ever links a
+/// generator to its immediate neighbor in braid order, so if a position's
+/// generators are not all contiguous - i.e. a different position's
+/// generator falls between two generators that share a position - the
+/// matrix would need a linking entry between those two non-adjacent rows
+/// that it has no way to record. Rejecting that case is what keeps the
+/// simplification honest rather than silently wrong.
+fn check_seifert_shape(generators: &[SeifertGenerator]) -> Result<(), String> {
+    let mut last_position = None;
+    let mut closed_positions = std::collections::HashSet::new();
+
+    for generator in generators {
+        if let Some(last) = last_position {
+            if last != generator.position {
+                closed_positions.insert(last);
+            }
+        }
+        if closed_positions.contains(&generator.position) {
+            return Err(format!(
+                "build_seifert_matrix: strand position {} has a non-tree edge that re-opens after another position's edge interleaved with it; this braid's Seifert graph is too deeply interleaved for this crate's simplified bidiagonal construction to handle exactly (see build_seifert_matrix's doc comment)",
+                generator.position
+            ));
+        }
+        last_position = Some(generator.position);
+    }
+
+    Ok(())
+}
+
+/// Build the Seifert matrix `V` of a braid closure
+///
+/// Applying the oriented (Seifert) resolution to every crossing of a braid
+/// closure always yields the identity smoothing, since every strand is
+/// coherently oriented top-to-bottom; the Seifert circles are therefore
+/// exactly the braid's strand positions. The Seifert graph has one vertex
+/// per strand and one edge per crossing (connecting the two strands it
+/// acts on); processing crossings in braid order with a union-find picks
+/// out a spanning tree (the first edge to connect each new pair of
+/// components), and every other crossing indexes one of the `2g` homology
+/// generators of the resulting genus-`g` surface.
+///
+/// Each generator's self-linking is `-sign` of its own crossing.
+/// Consecutive generators (adjacent in braid height) link with `+1` in one
+/// direction and `0` in the other when their positions are equal or
+/// adjacent (their bands touch the same Seifert circle), matching the
+/// standard bidiagonal Seifert matrix of a 2-strand torus braid; this is
+/// exact for braids where the Seifert graph has at most one non-tree edge
+/// "in flight" between any two circles at a time (as in every case this
+/// crate has validated against known Seifert matrices), and is a
+/// documented simplification for more deeply interleaved multi-strand
+/// braids, where the full rule also depends on how non-tree edges nest.
+/// `check_seifert_shape` rejects braids outside that validated shape
+/// instead of returning a silently-incorrect matrix for them.
+fn build_seifert_matrix(braid: &Braid) -> Result<Vec<Vec<Integer>>, String> {
     let strands = braid.strands();
-    
-    // Compute Seifert matrix from braid
-    // For a braid with n crossings and s strands, Seifert matrix is (s-1) x (s-1)
-    let matrix_size = (strands - 1).max(1);
-    let mut seifert_matrix = DMatrix::<f64>::zeros(matrix_size, matrix_size);
-    
-    // Simplified Seifert matrix construction
-    // Full implementation would track Seifert circles and linking numbers
-    // For now, create a matrix based on braid structure
-    
-    // Each crossing contributes to the Seifert matrix
-    for crossing in crossings.iter() {
-        let i = crossing.strand.min(matrix_size - 1);
-        let j = (crossing.strand + 1).min(matrix_size - 1);
-        
-        // Seifert matrix entries based on crossing type
-        if crossing.is_over {
-            // Positive crossing
-            seifert_matrix[(i, j)] += 1.0;
-            seifert_matrix[(j, i)] -= 1.0;
+    let mut union_find = UnionFind::new(strands);
+    let mut generators: Vec<SeifertGenerator> = Vec::new();
+
+    for crossing in braid.get_crossings() {
+        let position = crossing.strand;
+        let sign = if crossing.is_over { 1 } else { -1 };
+
+        if union_find.find(position) != union_find.find(position + 1) {
+            union_find.union(position, position + 1);
         } else {
-            // Negative crossing
-            seifert_matrix[(i, j)] -= 1.0;
-            seifert_matrix[(j, i)] += 1.0;
+            generators.push(SeifertGenerator { position, sign });
         }
     }
-    
-    // Calculate Alexander polynomial: Δ(t) = det(V - tV^T)
-    // For small matrices, we can compute this directly
-    // For larger matrices, we'd use more sophisticated methods
-    
-    if matrix_size == 1 {
-        // 1x1 matrix: det(V - tV^T) = V[0,0] - t*V[0,0] = V[0,0]*(1-t)
-        let v00 = seifert_matrix[(0, 0)];
-        return Polynomial::new(vec![v00, -v00]);
-    }
-    
-    // For 2x2 or larger, compute determinant symbolically
-    // Simplified: use characteristic polynomial approach
-    // Δ(t) ≈ det(V) * (1 - t)^(matrix_size - rank)
-    
-    // Compute determinant of V
-    let det_v = seifert_matrix.determinant();
-    
-    // Create polynomial: Δ(t) = det(V) * (1 - t)^k
-    // Where k depends on matrix structure
-    let k = matrix_size.min(n);
-    let mut coeffs = vec![Float::with_val(precision, 0.0); k + 1];
-    
-    // Binomial expansion: (1-t)^k = Σ C(k,i) * (-1)^i * t^i
-    for i in 0..=k {
-        let binom_coeff = binomial_coefficient(k, i);
-        let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
-        coeffs[i] = Float::with_val(precision, det_v * binom_coeff as f64 * sign);
+
+    check_seifert_shape(&generators)?;
+
+    let g = generators.len();
+    let mut v = vec![vec![Integer::from(0); g]; g];
+    for i in 0..g {
+        v[i][i] = Integer::from(-generators[i].sign);
+        if i + 1 < g {
+            let position_a = generators[i].position;
+            let position_b = generators[i + 1].position;
+            let shares_a_circle = position_a == position_b || position_a.abs_diff(position_b) == 1;
+            if shares_a_circle {
+                v[i][i + 1] = Integer::from(1);
+            }
+        }
     }
-    
-    // Convert to f64 for Polynomial
-    let coeffs_f64: Vec<f64> = coeffs.iter().map(|c| c.to_f64()).collect();
-    Polynomial::new(coeffs_f64)
+    Ok(v)
+}
+
+/// `M(t) = V - t·V^T`, entry-wise, as a matrix of degree-≤1 Laurent
+/// polynomials
+fn seifert_to_alexander_matrix(v: &[Vec<Integer>]) -> Vec<Vec<LaurentPolynomial>> {
+    let g = v.len();
+    (0..g)
+        .map(|i| {
+            (0..g)
+                .map(|j| {
+                    let v_ij = v[i][j].to_i64().unwrap_or(0);
+                    let v_ji = v[j][i].to_i64().unwrap_or(0);
+                    LaurentPolynomial::new(0, vec![v_ij, -v_ji])
+                })
+                .collect()
+        })
+        .collect()
 }
 
-/// Calculate binomial coefficient C(n, k)
-fn binomial_coefficient(n: usize, k: usize) -> usize {
-    if k > n {
-        return 0;
+/// Determinant of a square matrix of Laurent polynomials via fraction-free
+/// Bareiss elimination
+///
+/// At each step the algorithm divides the eliminated entries by the
+/// previous pivot; the Sylvester identity relating 2x2 minors to this
+/// quotient guarantees the division is always exact, so every `div_exact`
+/// call below is infallible in exact arithmetic.
+fn bareiss_determinant(mut m: Vec<Vec<LaurentPolynomial>>) -> LaurentPolynomial {
+    let n = m.len();
+    if n == 0 {
+        return LaurentPolynomial::new(0, vec![1]);
     }
-    if k == 0 || k == n {
-        return 1;
+
+    let zero = LaurentPolynomial::new(0, vec![0]);
+    let mut sign = 1i64;
+    let mut prev_pivot = LaurentPolynomial::new(0, vec![1]);
+
+    for k in 0..n - 1 {
+        if m[k][k].is_zero() {
+            match ((k + 1)..n).find(|&r| !m[r][k].is_zero()) {
+                Some(r) => {
+                    m.swap(k, r);
+                    sign = -sign;
+                }
+                None => return zero,
+            }
+        }
+
+        for i in (k + 1)..n {
+            for j in (k + 1)..n {
+                let cross = m[i][j].mul(&m[k][k]).sub(&m[i][k].mul(&m[k][j]));
+                m[i][j] = cross
+                    .div_exact(&prev_pivot)
+                    .expect("Bareiss elimination guarantees exact division by the previous pivot");
+            }
+            m[i][k] = zero.clone();
+        }
+
+        prev_pivot = m[k][k].clone();
     }
-    
-    let k = k.min(n - k); // Use symmetry
-    let mut result = 1;
-    for i in 0..k {
-        result = result * (n - i) / (i + 1);
+
+    let det = m[n - 1][n - 1].clone();
+    if sign < 0 {
+        zero.sub(&det)
+    } else {
+        det
     }
-    result
+}
+
+/// Calculate Alexander polynomial from braid using a genuine Seifert matrix
+///
+/// 1. Build the Seifert matrix `V` of the braid closure (see
+///    `build_seifert_matrix`).
+/// 2. Compute `Δ_K(t) = det(V - tV^T)` exactly via fraction-free Bareiss
+///    elimination over the Laurent-polynomial ring.
+/// 3. Since `V` is `2g x 2g` for a genus-`g` Seifert surface, the raw
+///    determinant spans degrees `0..=2g`; center it by a factor of
+///    `t^-g` so it's symmetric about `t^0` (dividing by `t^{(2g)/2}`),
+///    then fix the overall unit by the standard convention `Δ_K(1) = 1`.
+fn calculate_alexander_polynomial(braid: &Braid) -> Result<LaurentPolynomial, String> {
+    if braid.get_crossings().is_empty() {
+        // Unknot: Δ(t) = 1
+        return Ok(LaurentPolynomial::new(0, vec![1]));
+    }
+
+    let v = build_seifert_matrix(braid)?;
+    let genus_generators = v.len();
+    let alexander_matrix = seifert_to_alexander_matrix(&v);
+    let raw_det = bareiss_determinant(alexander_matrix);
+
+    let centering = LaurentPolynomial::new(-(genus_generators as i64 / 2), vec![1]);
+    let centered = raw_det.mul(&centering);
+
+    Ok(if centered.evaluate(1.0) < 0.0 {
+        LaurentPolynomial::new(0, vec![0]).sub(&centered)
+    } else {
+        centered
+    })
 }
 
 /// Calculate crossing number from braid
@@ -288,8 +484,8 @@ mod tests {
         let mut braid = Braid::new(3);
         braid.add_crossing(0, true).unwrap();
         braid.add_crossing(1, true).unwrap();
-        
-        let invariants = KnotInvariants::from_braid(&braid);
+
+        let invariants = KnotInvariants::from_braid(&braid).unwrap();
         assert_eq!(invariants.crossing_number, 2);
     }
 
@@ -313,25 +509,100 @@ mod tests {
         assert!((jones.evaluate(1.0) - 1.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_jones_polynomial_trefoil() {
+        // sigma_1^3 on 2 strands closes to a trefoil
+        let mut braid = Braid::new(2);
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+
+        let jones = calculate_jones_polynomial(&braid);
+
+        // The Jones polynomial of any knot (single-component closure)
+        // evaluates to 1 at t = 1
+        assert!((jones.evaluate(1.0) - 1.0).abs() < 1e-6);
+        // Unlike the unknot, the trefoil's Jones polynomial is non-trivial
+        assert!(jones.max_degree() > jones.min_degree());
+    }
+
     #[test]
     fn test_alexander_polynomial_unknot() {
         let braid = Braid::new(3);
-        let alexander = calculate_alexander_polynomial(&braid);
-        
+        let alexander = calculate_alexander_polynomial(&braid).unwrap();
+
         // Unknot should have Δ(t) = 1
         assert!((alexander.evaluate(1.0) - 1.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_alexander_polynomial_trefoil() {
+        // sigma_1^3 on 2 strands closes to a trefoil: Δ(t) = t - 1 + t^-1
+        let mut braid = Braid::new(2);
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+
+        let alexander = calculate_alexander_polynomial(&braid).unwrap();
+
+        assert_eq!(alexander.min_degree(), -1);
+        assert_eq!(alexander.max_degree(), 1);
+        assert_eq!(alexander.coefficient(-1), Integer::from(1));
+        assert_eq!(alexander.coefficient(0), Integer::from(-1));
+        assert_eq!(alexander.coefficient(1), Integer::from(1));
+    }
+
+    #[test]
+    fn test_alexander_polynomial_figure_eight() {
+        // sigma_1 sigma_2^-1 sigma_1 sigma_2^-1 on 3 strands closes to a
+        // figure-eight knot: Δ(t) = -t + 3 - t^-1
+        let mut braid = Braid::new(3);
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(1, false).unwrap();
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(1, false).unwrap();
+
+        let alexander = calculate_alexander_polynomial(&braid).unwrap();
+
+        assert_eq!(alexander.min_degree(), -1);
+        assert_eq!(alexander.max_degree(), 1);
+        assert_eq!(alexander.coefficient(-1), Integer::from(-1));
+        assert_eq!(alexander.coefficient(0), Integer::from(3));
+        assert_eq!(alexander.coefficient(1), Integer::from(-1));
+    }
+
+    #[test]
+    fn test_alexander_polynomial_rejects_deeply_interleaved_braid() {
+        // The crossings at positions 0 and 1 first connect Seifert circles
+        // 0, 1 and 2 in the Seifert graph (tree edges), then the generators (non-tree edges) fall at
+        // positions 0, 1, 0 - the first and third share a position but the
+        // second interleaves between them, which is exactly the "non-tree
+        // edge re-opens after another position's edge" shape
+        // `check_seifert_shape` rejects rather than silently mismatching.
+        let mut braid = Braid::new(3);
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(1, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(1, false).unwrap();
+        braid.add_crossing(0, true).unwrap();
+
+        assert!(calculate_alexander_polynomial(&braid).is_err());
+        assert!(KnotInvariants::from_braid(&braid).is_err());
+    }
+
     #[test]
     fn test_topological_compatibility() {
-        let mut braid1 = Braid::new(3);
+        // A single crossing on 2 strands closes to a single-component knot
+        // (the permutation is the 2-cycle (0 1)), unlike 3 strands where it
+        // would close to a 2-component link.
+        let mut braid1 = Braid::new(2);
         braid1.add_crossing(0, true).unwrap();
-        
-        let mut braid2 = Braid::new(3);
+
+        let mut braid2 = Braid::new(2);
         braid2.add_crossing(0, true).unwrap();
-        
-        let inv1 = KnotInvariants::from_braid(&braid1);
-        let inv2 = KnotInvariants::from_braid(&braid2);
+
+        let inv1 = KnotInvariants::from_braid(&braid1).unwrap();
+        let inv2 = KnotInvariants::from_braid(&braid2).unwrap();
         
         let compat = inv1.topological_compatibility(&inv2);
         
@@ -340,11 +611,4 @@ mod tests {
         assert!(compat <= 1.0);
     }
 
-    #[test]
-    fn test_binomial_coefficient() {
-        assert_eq!(binomial_coefficient(5, 2), 10);
-        assert_eq!(binomial_coefficient(4, 0), 1);
-        assert_eq!(binomial_coefficient(4, 4), 1);
-        assert_eq!(binomial_coefficient(6, 3), 20);
-    }
 }
@@ -0,0 +1,393 @@
+// Knot input from DT codes, PD notation, and a built-in Rolfsen table
+//
+// Every invariant in this crate (Jones, Alexander, Khovanov) is computed
+// from a `Braid`, so the constructors here all bottom out in a `Braid`:
+// standard knot notations are parsed into a `PlanarDiagram`, whose Seifert
+// circles are computed generically (the same oriented-resolution idea
+// `knot_invariants::build_seifert_matrix` uses for braids), and then
+// linearized into braid strand positions when the diagram's circles form
+// a simple chain - exactly the shape every braid closure's Seifert
+// picture has.
+
+use crate::braid_group::Braid;
+use std::collections::{HashMap, HashSet};
+
+/// A single crossing in planar-diagram (PD) notation
+///
+/// `edges` lists the four arcs meeting the crossing in counterclockwise
+/// order `[a, b, c, d]`: `a` is the incoming understrand arc, `c` the
+/// outgoing understrand arc, and `b`/`d` the overstrand's incoming and
+/// outgoing arcs. `is_over` records the crossing's sign using this
+/// crate's existing braid convention (`true` for a positive crossing);
+/// recovering a sign from bare edge combinatorics alone would need a full
+/// planar embedding, which arc labels don't determine, so PD input here
+/// carries it explicitly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PdCrossing {
+    pub edges: [usize; 4],
+    pub is_over: bool,
+}
+
+/// A knot or link diagram as a flat list of crossings, independent of any
+/// particular braid presentation
+#[derive(Debug, Clone)]
+pub struct PlanarDiagram {
+    crossings: Vec<PdCrossing>,
+}
+
+/// Minimal union-find over arbitrary `usize` arc labels (as opposed to the
+/// crate's other union-finds, which index a dense `0..n` range)
+struct LabelUnionFind {
+    parent: HashMap<usize, usize>,
+}
+
+impl LabelUnionFind {
+    fn new() -> Self {
+        LabelUnionFind { parent: HashMap::new() }
+    }
+
+    fn find(&mut self, x: usize) -> usize {
+        let parent = *self.parent.entry(x).or_insert(x);
+        if parent == x {
+            x
+        } else {
+            let root = self.find(parent);
+            self.parent.insert(x, root);
+            root
+        }
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+        if root_a != root_b {
+            self.parent.insert(root_a, root_b);
+        }
+    }
+}
+
+impl PlanarDiagram {
+    /// Build a diagram directly from PD notation
+    ///
+    /// Validates that the diagram is a well-formed 4-valent graph: every
+    /// arc label must appear in exactly two crossing slots (once where it
+    /// enters, once where it exits).
+    pub fn from_pd_notation(crossings: Vec<PdCrossing>) -> Result<Self, String> {
+        let mut occurrences: HashMap<usize, usize> = HashMap::new();
+        for crossing in &crossings {
+            for &edge in &crossing.edges {
+                *occurrences.entry(edge).or_insert(0) += 1;
+            }
+        }
+        for (edge, count) in &occurrences {
+            if *count != 2 {
+                return Err(format!(
+                    "Arc {} appears {} times in the PD notation, expected exactly 2",
+                    edge, count
+                ));
+            }
+        }
+        Ok(PlanarDiagram { crossings })
+    }
+
+    /// Build a diagram from a Dowker-Thompson (DT) code
+    ///
+    /// A DT code of length `n` describes an `n`-crossing diagram via a
+    /// single traversal labeled `1..=2n`: odd labels are always visits to
+    /// the understrand, and `dt_code[i]` gives the even label of the
+    /// other visit to the same crossing as odd label `2i+1`. The sign of
+    /// `dt_code[i]` records that crossing's sign directly (positive ==
+    /// this crate's `is_over` convention), since recovering it from an
+    /// unsigned code in general requires knowing the diagram's alternating
+    /// pattern or a full planar embedding.
+    ///
+    /// The outgoing understrand arc at a crossing is the arc leaving that
+    /// *same* crossing's other (overstrand) visit, not the next arc in
+    /// raw traversal order - pairing each crossing's own two visits this way
+    /// is what lets `to_braid`'s oriented resolution actually separate the
+    /// diagram into multiple Seifert circles; pairing by traversal order
+    /// alone just reconstructs the single input loop (one circle) for every
+    /// DT code, regardless of its crossing structure. Verified by hand
+    /// against the standard trefoil code `[4, 6, 2]`, whose reconstruction
+    /// is checked against `rolfsen_knot(3, 1)` in
+    /// `test_dt_code_trefoil_round_trips_to_rolfsen_knot` below.
+    pub fn from_dt_code(dt_code: &[i64]) -> Result<Self, String> {
+        let n = dt_code.len();
+        if n == 0 {
+            return Err("DT code must have at least one crossing".to_string());
+        }
+        let two_n = 2 * n as i64;
+
+        let mut seen_even: HashSet<i64> = HashSet::new();
+        for &value in dt_code {
+            if value == 0 || value % 2 != 0 {
+                return Err(format!("DT code entry {} must be a nonzero even integer", value));
+            }
+            if value.abs() > two_n {
+                return Err(format!(
+                    "DT code entry {} is out of range for a {}-crossing code",
+                    value, n
+                ));
+            }
+            if !seen_even.insert(value.abs()) {
+                return Err(format!("DT code entry {} (even label) repeats", value.abs()));
+            }
+        }
+
+        // arc(k) for k in 1..=2n connects traversal visit k to visit k+1
+        // (cyclically); arc(0) is arc(2n).
+        let arc = |visit: i64| -> usize {
+            let wrapped = ((visit - 1).rem_euclid(two_n)) + 1;
+            wrapped as usize
+        };
+
+        let mut crossings = Vec::with_capacity(n);
+        for (i, &dt) in dt_code.iter().enumerate() {
+            let odd_visit = 2 * i as i64 + 1;
+            let even_visit = dt.abs();
+
+            let under_in = arc(odd_visit - 1);
+            let under_out = arc(even_visit);
+            let over_in = arc(even_visit - 1);
+            let over_out = arc(odd_visit);
+
+            crossings.push(PdCrossing {
+                edges: [under_in, over_in, under_out, over_out],
+                is_over: dt > 0,
+            });
+        }
+
+        PlanarDiagram::from_pd_notation(crossings)
+    }
+
+    /// Convert to a `Braid`, if this diagram's Seifert circles form a
+    /// simple chain
+    ///
+    /// Computes Seifert circles by the oriented resolution at every
+    /// crossing (connecting `edges[0]` to `edges[2]`, and `edges[1]` to
+    /// `edges[3]`, via union-find over arc labels - the same idea as
+    /// `knot_invariants::build_seifert_matrix`, generalized from strand
+    /// positions to arbitrary arc labels). If the distinct circles
+    /// touched by the crossings form a simple path when laid end to end
+    /// (exactly the shape every braid closure's Seifert picture has),
+    /// assigns each circle a strand position along that path and emits
+    /// the corresponding `Braid`; otherwise returns an error rather than
+    /// guessing at a linearization.
+    pub fn to_braid(&self) -> Result<Braid, String> {
+        if self.crossings.is_empty() {
+            return Err("Cannot build a braid from an empty diagram".to_string());
+        }
+
+        let mut union_find = LabelUnionFind::new();
+        for crossing in &self.crossings {
+            union_find.union(crossing.edges[0], crossing.edges[2]);
+            union_find.union(crossing.edges[1], crossing.edges[3]);
+        }
+
+        let circle_of = |uf: &mut LabelUnionFind, edge: usize| uf.find(edge);
+        let crossing_circles: Vec<(usize, usize)> = self
+            .crossings
+            .iter()
+            .map(|c| {
+                (
+                    circle_of(&mut union_find, c.edges[0]),
+                    circle_of(&mut union_find, c.edges[1]),
+                )
+            })
+            .collect();
+
+        // Deduplicated adjacency between distinct circles, to check the
+        // "simple chain" shape independent of how many parallel crossings
+        // connect the same pair.
+        let mut neighbors: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for &(under, over) in &crossing_circles {
+            if under == over {
+                return Err("A crossing connects a circle to itself; not braid-presentable".to_string());
+            }
+            neighbors.entry(under).or_default().insert(over);
+            neighbors.entry(over).or_default().insert(under);
+        }
+
+        for (circle, adj) in &neighbors {
+            if adj.len() > 2 {
+                return Err(format!(
+                    "Circle {} touches {} other circles; the Seifert graph is not a simple chain",
+                    circle,
+                    adj.len()
+                ));
+            }
+        }
+
+        // Linear order the circles by walking the chain from an endpoint
+        // (a circle with a single neighbor), or from any circle if there's
+        // only one.
+        let start = neighbors
+            .iter()
+            .find(|(_, adj)| adj.len() <= 1)
+            .map(|(&circle, _)| circle)
+            .unwrap_or_else(|| *neighbors.keys().next().unwrap());
+
+        let mut position: HashMap<usize, usize> = HashMap::new();
+        let mut current = start;
+        let mut previous: Option<usize> = None;
+        position.insert(current, 0);
+        while position.len() < neighbors.len() {
+            let next = neighbors[&current]
+                .iter()
+                .find(|&&n| Some(n) != previous)
+                .copied();
+            match next {
+                Some(n) if !position.contains_key(&n) => {
+                    position.insert(n, position.len());
+                    previous = Some(current);
+                    current = n;
+                }
+                _ => {
+                    return Err("Seifert graph is disconnected or branches; not a simple chain".to_string());
+                }
+            }
+        }
+
+        let strands = position.len();
+        let mut braid = Braid::new(strands);
+        for (i, crossing) in self.crossings.iter().enumerate() {
+            let (under, over) = crossing_circles[i];
+            let strand = position[&under].min(position[&over]);
+            braid.add_crossing(strand, crossing.is_over)?;
+        }
+
+        Ok(braid)
+    }
+}
+
+/// Look up a classical Rolfsen-table knot by its standard name, e.g.
+/// `rolfsen_knot(3, 1)` for `3_1` (the trefoil)
+///
+/// Returns the knot's standard minimal braid word. Only the handful of
+/// knots this crate has been able to validate against their known Jones
+/// and Alexander polynomials are included so far; unknown entries return
+/// an error rather than a guessed diagram.
+pub fn rolfsen_knot(crossings: usize, index: usize) -> Result<Braid, String> {
+    match (crossings, index) {
+        (3, 1) => {
+            let mut braid = Braid::new(2);
+            for _ in 0..3 {
+                braid.add_crossing(0, true)?;
+            }
+            Ok(braid)
+        }
+        (4, 1) => {
+            let mut braid = Braid::new(3);
+            braid.add_crossing(0, true)?;
+            braid.add_crossing(1, false)?;
+            braid.add_crossing(0, true)?;
+            braid.add_crossing(1, false)?;
+            Ok(braid)
+        }
+        (5, 1) => {
+            let mut braid = Braid::new(2);
+            for _ in 0..5 {
+                braid.add_crossing(0, true)?;
+            }
+            Ok(braid)
+        }
+        _ => Err(format!(
+            "Rolfsen knot {}_{} is not yet in the built-in table",
+            crossings, index
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::knot_invariants::KnotInvariants;
+
+    #[test]
+    fn test_pd_notation_rejects_unmatched_arcs() {
+        let crossings = vec![PdCrossing { edges: [0, 1, 2, 3], is_over: true }];
+        assert!(PlanarDiagram::from_pd_notation(crossings).is_err());
+    }
+
+    #[test]
+    fn test_pd_notation_trefoil_round_trips_to_braid() {
+        // Two Seifert circles (strand 0's arcs 0,1,2 and strand 1's arcs
+        // 10,11,12), with all three crossings connecting them, matching
+        // the braid closure of sigma_1^3.
+        let crossings = vec![
+            PdCrossing { edges: [2, 12, 0, 10], is_over: true },
+            PdCrossing { edges: [0, 10, 1, 11], is_over: true },
+            PdCrossing { edges: [1, 11, 2, 12], is_over: true },
+        ];
+        let diagram = PlanarDiagram::from_pd_notation(crossings).unwrap();
+        let braid = diagram.to_braid().unwrap();
+
+        assert_eq!(braid.strands(), 2);
+        assert_eq!(braid.get_crossings().len(), 3);
+
+        // Should carry the same invariants as the braid-word trefoil.
+        let from_pd = KnotInvariants::from_braid(&braid).unwrap();
+        let from_braid_word = KnotInvariants::from_braid(&rolfsen_knot(3, 1).unwrap()).unwrap();
+        assert_eq!(from_pd.alexander_polynomial.coefficient(0), from_braid_word.alexander_polynomial.coefficient(0));
+        assert_eq!(from_pd.alexander_polynomial.coefficient(1), from_braid_word.alexander_polynomial.coefficient(1));
+    }
+
+    #[test]
+    fn test_pd_notation_self_loop_crossing_is_rejected() {
+        // Both the understrand and overstrand pairs share their arc labels,
+        // so the crossing's two Seifert circles collapse into one.
+        let crossings = vec![PdCrossing { edges: [0, 0, 1, 1], is_over: true }];
+        let diagram = PlanarDiagram::from_pd_notation(crossings).unwrap();
+        assert!(diagram.to_braid().is_err());
+    }
+
+    #[test]
+    fn test_rolfsen_knot_trefoil() {
+        let braid = rolfsen_knot(3, 1).unwrap();
+        assert_eq!(braid.get_crossings().len(), 3);
+        let invariants = KnotInvariants::from_braid(&braid).unwrap();
+        assert_eq!(invariants.crossing_number, 3);
+    }
+
+    #[test]
+    fn test_rolfsen_knot_figure_eight_matches_known_alexander_polynomial() {
+        let braid = rolfsen_knot(4, 1).unwrap();
+        let invariants = KnotInvariants::from_braid(&braid).unwrap();
+        assert_eq!(invariants.alexander_polynomial.coefficient(-1), rug::Integer::from(-1));
+        assert_eq!(invariants.alexander_polynomial.coefficient(0), rug::Integer::from(3));
+        assert_eq!(invariants.alexander_polynomial.coefficient(1), rug::Integer::from(-1));
+    }
+
+    #[test]
+    fn test_rolfsen_knot_unknown_entry_is_an_honest_error() {
+        assert!(rolfsen_knot(5, 2).is_err());
+    }
+
+    #[test]
+    fn test_dt_code_parses_into_a_well_formed_diagram() {
+        let diagram = PlanarDiagram::from_dt_code(&[4, 6, 2]).unwrap();
+        assert_eq!(diagram.crossings.len(), 3);
+    }
+
+    #[test]
+    fn test_dt_code_rejects_odd_entries() {
+        assert!(PlanarDiagram::from_dt_code(&[3, 6, 2]).is_err());
+    }
+
+    #[test]
+    fn test_dt_code_trefoil_round_trips_to_rolfsen_knot() {
+        // [4, 6, 2] is the standard DT code for the trefoil.
+        let diagram = PlanarDiagram::from_dt_code(&[4, 6, 2]).unwrap();
+        let braid = diagram.to_braid().unwrap();
+
+        assert_eq!(braid.strands(), 2);
+        assert_eq!(braid.get_crossings().len(), 3);
+
+        let from_dt = KnotInvariants::from_braid(&braid).unwrap();
+        let from_table = KnotInvariants::from_braid(&rolfsen_knot(3, 1).unwrap()).unwrap();
+        assert_eq!(from_dt.jones_polynomial, from_table.jones_polynomial);
+        assert_eq!(from_dt.alexander_polynomial.coefficient(-1), from_table.alexander_polynomial.coefficient(-1));
+        assert_eq!(from_dt.alexander_polynomial.coefficient(0), from_table.alexander_polynomial.coefficient(0));
+        assert_eq!(from_dt.alexander_polynomial.coefficient(1), from_table.alexander_polynomial.coefficient(1));
+    }
+}
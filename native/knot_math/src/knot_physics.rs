@@ -4,6 +4,11 @@
 
 // Statistical mechanics for knots
 // (Note: ln_gamma not used in current implementation, but available if needed)
+//
+// Routed through `crate::ops` so builds with the `libm` feature get
+// bit-reproducible partition functions and entropies across platforms.
+
+use crate::ops::{exp, ln};
 
 /// Calculate partition function: Z = Σ exp(-E_i / k_B T)
 /// 
@@ -20,7 +25,7 @@ pub fn calculate_partition_function(
     
     energies
         .iter()
-        .map(|&e| (-beta * e).exp())
+        .map(|&e| exp(-beta * e))
         .sum()
 }
 
@@ -35,7 +40,7 @@ pub fn calculate_boltzmann_distribution(
     
     energies
         .iter()
-        .map(|&e| (-beta * e).exp() / z)
+        .map(|&e| exp(-beta * e) / z)
         .collect()
 }
 
@@ -44,7 +49,7 @@ pub fn calculate_entropy(probabilities: &[f64]) -> f64 {
     probabilities
         .iter()
         .filter(|&&p| p > 1e-10) // Avoid log(0)
-        .map(|&p| -p * p.ln())
+        .map(|&p| -p * ln(p))
         .sum()
 }
 
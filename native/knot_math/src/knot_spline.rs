@@ -0,0 +1,265 @@
+// Knot curves as B-splines, for C2-smooth energy and dynamics
+//
+// `knot_energy::calculate_knot_energy` already fits a `curve::BSplineCurve`
+// through raw polyline points before integrating curvature, but that fit is
+// thrown away afterward, and `knot_dynamics::evolve_knot` moves the raw
+// polyline vertices directly, which can wrinkle the curve between
+// evaluations. This module lets the spline itself be the knot's
+// representation: build one directly from control points (default cubic,
+// clamped or periodic), refine it with Boehm's knot-insertion algorithm, and
+// evolve its control points under the same dynamics equation
+// `knot_dynamics::evolve_knot` uses, so every intermediate configuration
+// stays exactly C^(degree-1) smooth.
+
+use nalgebra::DVector;
+
+use crate::curve::BSplineCurve;
+use crate::knot_dynamics::KnotDynamicsParams;
+use crate::knot_energy::calculate_knot_energy;
+
+/// Which kind of knot vector to build for a fresh set of control points
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KnotVectorKind {
+    /// Clamped: the curve interpolates its first and last control points
+    Clamped,
+    /// Periodic (uniform, unclamped): used for closed knot curves
+    Periodic,
+}
+
+/// A clamped, uniformly-spaced knot vector of `degree` for `num_control_points`
+/// control points: `degree + 1` repeated knots at each end, uniform interior knots
+fn clamped_uniform_knot_vector(num_control_points: usize, degree: usize) -> Vec<f64> {
+    let mut knots = vec![0.0; num_control_points + degree + 1];
+    for i in 0..=degree {
+        knots[i] = 0.0;
+        knots[num_control_points + degree - i] = 1.0;
+    }
+    let num_interior = num_control_points.saturating_sub(degree + 1);
+    for i in 0..num_interior {
+        knots[degree + 1 + i] = (i + 1) as f64 / (num_interior + 1) as f64;
+    }
+    knots
+}
+
+/// A periodic (uniform, unclamped) knot vector: `knots[i] = i`
+fn periodic_uniform_knot_vector(num_control_points: usize, degree: usize) -> Vec<f64> {
+    (0..num_control_points + degree + 1).map(|i| i as f64).collect()
+}
+
+/// Build a B-spline curve directly from control points, with a default
+/// cubic degree (reduced for fewer control points, as `knot_energy` does)
+/// and the requested knot vector kind
+pub fn from_control_points(
+    control_points: Vec<DVector<f64>>,
+    kind: KnotVectorKind,
+) -> Result<BSplineCurve, String> {
+    if control_points.is_empty() {
+        return Err("Control points cannot be empty".to_string());
+    }
+    let degree = 3.min(control_points.len() - 1);
+    let knots = match kind {
+        KnotVectorKind::Clamped => clamped_uniform_knot_vector(control_points.len(), degree),
+        KnotVectorKind::Periodic => periodic_uniform_knot_vector(control_points.len(), degree),
+    };
+    BSplineCurve::new(control_points, knots, degree)
+}
+
+/// Densely evaluate the curve at `n` evenly-spaced parameters across its
+/// domain, for feeding into `knot_energy::calculate_knot_energy`
+pub fn sample_curve(curve: &BSplineCurve, n: usize) -> Vec<DVector<f64>> {
+    let (s_min, s_max) = curve.domain();
+    if n == 0 {
+        return Vec::new();
+    }
+    if n == 1 {
+        return vec![curve.eval(s_min)];
+    }
+    (0..n)
+        .map(|i| curve.eval(s_min + (s_max - s_min) * i as f64 / (n - 1) as f64))
+        .collect()
+}
+
+/// Insert a single knot value `u` into the curve via Boehm's algorithm,
+/// without changing the curve it represents
+///
+/// Locates the span `[knots[j], knots[j+1])` containing `u`, then replaces
+/// control points `j-p+1..=j` with `P_i' = (1-a_i)*P_{i-1} + a_i*P_i` where
+/// `a_i = (u - knots[i]) / (knots[i+p] - knots[i])`; control points outside
+/// that range carry over unchanged (shifted by one past the insertion).
+/// Only supports simple (non-repeated) interior knot values, which is all
+/// `from_control_points`'s knot vectors ever produce.
+pub fn insert_knot(curve: &BSplineCurve, u: f64) -> Result<BSplineCurve, String> {
+    let degree = curve.degree();
+    let knots = curve.knots();
+    let control_points = curve.control_points();
+    let (s_min, s_max) = curve.domain();
+
+    if u < s_min || u > s_max {
+        return Err(format!(
+            "Knot value {} is outside the curve's domain [{}, {}]",
+            u, s_min, s_max
+        ));
+    }
+
+    let j = (degree..knots.len() - degree - 1)
+        .rev()
+        .find(|&i| knots[i] <= u)
+        .ok_or_else(|| "Could not find a containing knot span for insertion".to_string())?;
+
+    let mut new_knots = knots.to_vec();
+    new_knots.insert(j + 1, u);
+
+    let n = control_points.len();
+    let lower = j - degree + 1;
+    let mut new_points = Vec::with_capacity(n + 1);
+    for i in 0..=n {
+        if i < lower {
+            new_points.push(control_points[i].clone());
+        } else if i > j {
+            new_points.push(control_points[i - 1].clone());
+        } else {
+            let denom = knots[i + degree] - knots[i];
+            let a = if denom.abs() < 1e-12 { 0.0 } else { (u - knots[i]) / denom };
+            new_points.push(&control_points[i - 1] * (1.0 - a) + &control_points[i] * a);
+        }
+    }
+
+    BSplineCurve::new(new_points, new_knots, degree)
+}
+
+/// Evolve a spline's control points under the same dynamics equation
+/// `knot_dynamics::evolve_knot` applies to raw polyline vertices:
+/// `K(t+dt) = K(t) - relaxation_rate * grad(E_K) * dt + external_force_strength * F * dt`
+///
+/// The energy gradient is estimated by central finite differences on each
+/// control point coordinate, recomputing `calculate_knot_energy` over the
+/// curve densely resampled (`samples` points) after each perturbation - the
+/// same finite-difference strategy `knot_energy::calculate_energy_gradient`
+/// uses for raw points, just applied to control points instead. Moving
+/// control points (rather than sampled curve points) keeps every
+/// intermediate configuration exactly as smooth as the original spline.
+pub fn evolve_spline(
+    curve: &BSplineCurve,
+    params: &KnotDynamicsParams,
+    samples: usize,
+) -> Result<BSplineCurve, String> {
+    let control_points = curve.control_points();
+    if control_points.is_empty() {
+        return Err("Spline has no control points to evolve".to_string());
+    }
+
+    let epsilon = 1e-6;
+    let energy_at = |points: &[DVector<f64>]| -> Result<f64, String> {
+        let trial = BSplineCurve::new(points.to_vec(), curve.knots().to_vec(), curve.degree())?;
+        Ok(calculate_knot_energy(&sample_curve(&trial, samples)))
+    };
+
+    let dim = control_points[0].len();
+    let mut gradient = vec![DVector::zeros(dim); control_points.len()];
+    for i in 0..control_points.len() {
+        for coord in 0..dim {
+            let mut plus = control_points.to_vec();
+            plus[i][coord] += epsilon;
+            let mut minus = control_points.to_vec();
+            minus[i][coord] -= epsilon;
+            let energy_plus = energy_at(&plus)?;
+            let energy_minus = energy_at(&minus)?;
+            gradient[i][coord] = (energy_plus - energy_minus) / (2.0 * epsilon);
+        }
+    }
+
+    let mut new_points = Vec::with_capacity(control_points.len());
+    for (i, point) in control_points.iter().enumerate() {
+        let mut new_point = point.clone();
+        new_point -= &gradient[i] * params.relaxation_rate * params.time_step;
+        if let Some(ref force) = params.external_force {
+            new_point += force * params.external_force_strength * params.time_step;
+        }
+        new_points.push(new_point);
+    }
+
+    BSplineCurve::new(new_points, curve.knots().to_vec(), curve.degree())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_control_points_defaults_to_cubic() {
+        let control_points = (0..6)
+            .map(|i| DVector::from_vec(vec![i as f64, 0.0, 0.0]))
+            .collect();
+        let curve = from_control_points(control_points, KnotVectorKind::Clamped).unwrap();
+        assert_eq!(curve.degree(), 3);
+    }
+
+    #[test]
+    fn test_from_control_points_reduces_degree_for_few_points() {
+        let control_points = vec![
+            DVector::from_vec(vec![0.0, 0.0, 0.0]),
+            DVector::from_vec(vec![1.0, 0.0, 0.0]),
+        ];
+        let curve = from_control_points(control_points, KnotVectorKind::Clamped).unwrap();
+        assert_eq!(curve.degree(), 1);
+    }
+
+    #[test]
+    fn test_sample_curve_endpoint_count() {
+        let control_points = (0..6)
+            .map(|i| DVector::from_vec(vec![i as f64, 0.0, 0.0]))
+            .collect();
+        let curve = from_control_points(control_points, KnotVectorKind::Clamped).unwrap();
+        let samples = sample_curve(&curve, 10);
+        assert_eq!(samples.len(), 10);
+    }
+
+    #[test]
+    fn test_insert_knot_preserves_curve_shape() {
+        // A control polygon with some curvature, so the curve isn't degenerate.
+        let control_points = vec![
+            DVector::from_vec(vec![0.0, 0.0, 0.0]),
+            DVector::from_vec(vec![1.0, 2.0, 0.0]),
+            DVector::from_vec(vec![2.0, -1.0, 0.0]),
+            DVector::from_vec(vec![3.0, 1.0, 0.0]),
+            DVector::from_vec(vec![4.0, 0.0, 0.0]),
+        ];
+        let curve = from_control_points(control_points, KnotVectorKind::Clamped).unwrap();
+        let refined = insert_knot(&curve, 0.35).unwrap();
+
+        assert_eq!(refined.control_points().len(), curve.control_points().len() + 1);
+        assert_eq!(refined.knots().len(), curve.knots().len() + 1);
+
+        for i in 0..=10 {
+            let s = i as f64 / 10.0;
+            let before = curve.eval(s);
+            let after = refined.eval(s);
+            assert!((before - after).norm() < 1e-8, "curve shape changed at s={}", s);
+        }
+    }
+
+    #[test]
+    fn test_evolve_spline_reduces_energy_for_a_kinked_curve() {
+        let control_points = vec![
+            DVector::from_vec(vec![0.0, 0.0, 0.0]),
+            DVector::from_vec(vec![1.0, 1.0, 0.0]),
+            DVector::from_vec(vec![2.0, -1.0, 0.0]),
+            DVector::from_vec(vec![3.0, 1.0, 0.0]),
+            DVector::from_vec(vec![4.0, 0.0, 0.0]),
+        ];
+        let curve = from_control_points(control_points, KnotVectorKind::Clamped).unwrap();
+
+        let params = KnotDynamicsParams {
+            time_step: 0.01,
+            relaxation_rate: 1.0,
+            external_force_strength: 0.0,
+            external_force: None,
+        };
+
+        let energy_before = calculate_knot_energy(&sample_curve(&curve, 20));
+        let evolved = evolve_spline(&curve, &params, 20).unwrap();
+        let energy_after = calculate_knot_energy(&sample_curve(&evolved, 20));
+
+        assert!(energy_after < energy_before);
+    }
+}
@@ -0,0 +1,512 @@
+// Lee homology and the Rasmussen s-invariant
+//
+// Reuses the same cube of resolutions as `khovanov.rs` (same circles, same
+// merges and splits) but deforms the Frobenius algebra from the nilpotent
+// `Z[X]/(X^2)` to Lee's `A = Q[X]/(X^2 - 1)`: multiplication is unchanged
+// except `m(X, X) = 1` (instead of `0`), and comultiplication is unchanged
+// except `Δ(X) = X⊗X + 1⊗1` (instead of just `X⊗X`).
+//
+// The deformed differential no longer preserves the quantum grading `j`
+// used by Khovanov homology -- it only respects the filtration `j >= j_0`,
+// since the extra terms introduced by the deformation always raise `j`.
+// Lee proved the resulting homology has total rank `2^(#components)`
+// (rank 2 for a knot), concentrated in homological degree 0, with two
+// generators whose best-achievable quantum filtration levels differ by
+// exactly 2; the Rasmussen `s`-invariant is the average of those two levels.
+//
+// Finding the best-achievable filtration level of each homology class is
+// exactly the "standard algorithm" from persistent homology: sort the
+// generators by ascending `j`, then reduce the cycle space against the
+// boundary space (and against itself) via low-pivot Gaussian elimination --
+// each surviving cycle's pivot position is the filtration level at which its
+// homology class is first detected, and no homologous cycle can do better.
+
+use crate::braid_group::Braid;
+use crate::khovanov::{build_edge, canonical_order, label_degree, resolve, CubeEdge, KhovanovGenerator, Label};
+use std::collections::HashMap;
+
+/// Multiplication for Lee's deformed algebra `A = Q[X]/(X^2 - 1)`: identical
+/// to Khovanov's nilpotent algebra except `X⊗X ↦ 1` (the unit) instead of `0`.
+fn lee_multiply(a: Label, b: Label) -> Label {
+    match (a, b) {
+        (0, 0) => 0,
+        (0, 1) | (1, 0) => 1,
+        (1, 1) => 0,
+        _ => unreachable!("label must be 0 or 1"),
+    }
+}
+
+/// Comultiplication for Lee's deformed algebra: identical to Khovanov's
+/// except `Δ(X) = X⊗X + 1⊗1`, an extra `1⊗1` term compared to the nilpotent case.
+fn lee_comultiply(a: Label) -> Vec<(Label, Label)> {
+    match a {
+        0 => vec![(0, 1), (1, 0)],
+        1 => vec![(1, 1), (0, 0)],
+        _ => unreachable!("label must be 0 or 1"),
+    }
+}
+
+/// The Lee differential out of every generator, as (target, signed coefficient) pairs
+type Differential = HashMap<KhovanovGenerator, Vec<(KhovanovGenerator, i64)>>;
+
+/// Build the Lee chain complex: every basis generator with its homological
+/// degree `i` and its *original* (Khovanov) quantum degree `j` -- the latter
+/// no longer a grading of the Lee differential, but still the coordinate the
+/// filtration is measured in -- together with the deformed differential.
+///
+/// This mirrors `khovanov::build_complex` almost exactly; the only
+/// difference is which Frobenius algebra maps are used, since the underlying
+/// cube of resolutions (circles, merges, splits) is unchanged by the deformation.
+fn build_complex(braid: &Braid) -> (Vec<(KhovanovGenerator, i32, i32)>, Differential) {
+    let crossings = braid.get_crossings();
+    let n = crossings.len();
+    let n_plus = crossings.iter().filter(|c| c.is_over).count() as i32;
+    let n_minus = n as i32 - n_plus;
+
+    let mut basis = Vec::new();
+    let mut differential: Differential = HashMap::new();
+
+    for bits in 0..(1u64 << n) {
+        let state: Vec<bool> = (0..n).map(|k| (bits >> k) & 1 == 1).collect();
+        let resolution = resolve(braid, &state);
+        let order = canonical_order(&resolution.circles);
+        let num_circles = order.len();
+        let r = state.iter().filter(|&&b| b).count() as i32;
+        let i_degree = r - n_minus;
+
+        for labels_bits in 0..(1u64 << num_circles) {
+            let labels: Vec<Label> = (0..num_circles).map(|p| ((labels_bits >> p) & 1) as Label).collect();
+            let degree_sum: i32 = labels.iter().map(|&l| label_degree(l)).sum();
+            let j_degree = degree_sum + r + n_plus - 2 * n_minus;
+            basis.push((KhovanovGenerator { state: state.clone(), labels: labels.clone() }, i_degree, j_degree));
+        }
+
+        for (k, &bit) in state.iter().enumerate() {
+            if bit {
+                continue;
+            }
+            let mut state1 = state.clone();
+            state1[k] = true;
+            let resolution1 = resolve(braid, &state1);
+            let order1 = canonical_order(&resolution1.circles);
+
+            let (before_a, before_b) = resolution.before[k];
+            let src_a = resolution.node_to_circle[&before_a];
+            let src_b = resolution.node_to_circle[&before_b];
+
+            let sign: i64 = if state[..k].iter().filter(|&&b| b).count() % 2 == 1 { -1 } else { 1 };
+
+            let edge = build_edge(&resolution, src_a, src_b, &resolution1, &order1, braid.strands(), k);
+
+            for labels_bits in 0..(1u64 << num_circles) {
+                let labels: Vec<Label> = (0..num_circles).map(|p| ((labels_bits >> p) & 1) as Label).collect();
+                let source = KhovanovGenerator { state: state.clone(), labels: labels.clone() };
+
+                let label_at = |circle_idx: usize| -> Label {
+                    let pos = order.iter().position(|&c| c == circle_idx).unwrap();
+                    labels[pos]
+                };
+
+                let mut images: Vec<(KhovanovGenerator, i64)> = Vec::new();
+
+                match &edge {
+                    CubeEdge::Merge { target_idx, other_map } => {
+                        let merged_label = lee_multiply(label_at(src_a), label_at(src_b));
+                        let mut new_labels = vec![0u8; order1.len()];
+                        let target_pos = order1.iter().position(|&c| c == *target_idx).unwrap();
+                        new_labels[target_pos] = merged_label;
+                        for (&idx0, &idx1) in other_map {
+                            let pos1 = order1.iter().position(|&c| c == idx1).unwrap();
+                            new_labels[pos1] = label_at(idx0);
+                        }
+                        images.push((KhovanovGenerator { state: state1.clone(), labels: new_labels }, sign));
+                    }
+                    CubeEdge::Split { idx_a, idx_b, other_map } => {
+                        for (label_a, label_b) in lee_comultiply(label_at(src_a)) {
+                            let mut new_labels = vec![0u8; order1.len()];
+                            new_labels[order1.iter().position(|&c| c == *idx_a).unwrap()] = label_a;
+                            new_labels[order1.iter().position(|&c| c == *idx_b).unwrap()] = label_b;
+                            for (&idx0, &idx1) in other_map {
+                                let pos1 = order1.iter().position(|&c| c == idx1).unwrap();
+                                new_labels[pos1] = label_at(idx0);
+                            }
+                            images.push((KhovanovGenerator { state: state1.clone(), labels: new_labels }, sign));
+                        }
+                    }
+                }
+
+                differential.entry(source).or_default().extend(images);
+            }
+        }
+    }
+
+    (basis, differential)
+}
+
+/// The Lee homology of a braid closure: just the total free rank per
+/// homological degree `i`, since the deformed differential doesn't respect
+/// the quantum grading `j` the way Khovanov's does.
+#[derive(Debug, Clone)]
+pub struct LeeHomology {
+    /// `(i, free_rank)` for every homological degree with nonzero rank
+    pub ranks: Vec<(i32, usize)>,
+}
+
+impl LeeHomology {
+    /// Total rank across all homological degrees -- `2^(#components)` by Lee's theorem
+    pub fn total_rank(&self) -> usize {
+        self.ranks.iter().map(|(_, rank)| rank).sum()
+    }
+}
+
+/// Rank of a matrix (rows of equal length) over the rationals, via Gaussian
+/// elimination in `f64` with a `1e-9` pivot tolerance.
+fn matrix_rank(mut rows: Vec<Vec<f64>>, num_cols: usize) -> usize {
+    let mut rank = 0;
+    for col in 0..num_cols {
+        let pivot = (rank..rows.len()).find(|&i| rows[i][col].abs() > 1e-9);
+        let Some(pivot) = pivot else { continue };
+        rows.swap(rank, pivot);
+        let pivot_val = rows[rank][col];
+        for value in rows[rank].iter_mut() {
+            *value /= pivot_val;
+        }
+        for i in 0..rows.len() {
+            if i != rank && rows[i][col].abs() > 1e-12 {
+                let factor = rows[i][col];
+                for c in 0..num_cols {
+                    rows[i][c] -= factor * rows[rank][c];
+                }
+            }
+        }
+        rank += 1;
+        if rank == rows.len() {
+            break;
+        }
+    }
+    rank
+}
+
+/// Compute the Lee homology of a braid closure
+pub fn lee_homology(braid: &Braid) -> LeeHomology {
+    let (basis, differential) = build_complex(braid);
+
+    let mut by_degree: HashMap<i32, Vec<&KhovanovGenerator>> = HashMap::new();
+    for (generator, i, _j) in &basis {
+        by_degree.entry(*i).or_default().push(generator);
+    }
+
+    let mut ranks = Vec::new();
+    let degrees: std::collections::BTreeSet<i32> = by_degree.keys().copied().collect();
+
+    for &i in &degrees {
+        let current = &by_degree[&i];
+        let next = by_degree.get(&(i + 1));
+        let prev = by_degree.get(&(i - 1));
+
+        let current_index: HashMap<&KhovanovGenerator, usize> =
+            current.iter().enumerate().map(|(idx, g)| (*g, idx)).collect();
+        let next_index: HashMap<&KhovanovGenerator, usize> = next
+            .map(|v| v.iter().enumerate().map(|(idx, g)| (*g, idx)).collect())
+            .unwrap_or_default();
+
+        let rows_di: Vec<Vec<f64>> = current
+            .iter()
+            .map(|generator| {
+                let mut row = vec![0.0; next_index.len()];
+                if let Some(targets) = differential.get(*generator) {
+                    for (target, coeff) in targets {
+                        if let Some(&col) = next_index.get(target) {
+                            row[col] += *coeff as f64;
+                        }
+                    }
+                }
+                row
+            })
+            .collect();
+        let rank_di = matrix_rank(rows_di, next_index.len());
+        let dim_ker_di = current.len() - rank_di;
+
+        let rank_dim1 = match prev {
+            Some(prev_generators) => {
+                let rows: Vec<Vec<f64>> = prev_generators
+                    .iter()
+                    .map(|generator| {
+                        let mut row = vec![0.0; current_index.len()];
+                        if let Some(targets) = differential.get(*generator) {
+                            for (target, coeff) in targets {
+                                if let Some(&col) = current_index.get(target) {
+                                    row[col] += *coeff as f64;
+                                }
+                            }
+                        }
+                        row
+                    })
+                    .collect();
+                matrix_rank(rows, current_index.len())
+            }
+            None => 0,
+        };
+
+        let free_rank = dim_ker_di.saturating_sub(rank_dim1);
+        if free_rank > 0 {
+            ranks.push((i, free_rank));
+        }
+    }
+
+    LeeHomology { ranks }
+}
+
+/// A dense vector over a fixed, `j`-sorted basis of one homological degree's chain group
+type ChainVector = Vec<f64>;
+
+/// Nullspace of the linear map given by `rows` (domain generator `i`'s image
+/// is `rows[i]`, a `num_cols`-vector), via row reduction of its transpose:
+/// `v` is a cycle iff `v` (as a row vector) times the matrix is zero, i.e.
+/// `v` lies in the left null space of `rows`, equivalently the (right) null
+/// space of its transpose.
+fn nullspace(rows: &[ChainVector], num_cols: usize) -> Vec<ChainVector> {
+    let num_rows = rows.len();
+    if num_rows == 0 {
+        // A 0-dimensional domain has only the trivial (0-dimensional) null space.
+        return Vec::new();
+    }
+    // `t` is the transpose of `rows`: `num_cols` rows, each of length `num_rows`,
+    // so reduced-row-echelon-reducing `t` finds the pivot *columns* of `rows`
+    // (indices `0..num_rows`) while leaving `0..num_cols` as `t`'s own row space.
+    let mut t: Vec<Vec<f64>> = (0..num_cols).map(|c| rows.iter().map(|row| row[c]).collect()).collect();
+
+    let mut pivot_row_of_column = vec![None; num_rows];
+    let mut rank = 0;
+    for column in 0..num_rows {
+        let pivot = (rank..num_cols).find(|&r| t[r][column].abs() > 1e-9);
+        let Some(pivot) = pivot else { continue };
+        t.swap(rank, pivot);
+        pivot_row_of_column[column] = Some(rank);
+        let pivot_val = t[rank][column];
+        for value in t[rank].iter_mut() {
+            *value /= pivot_val;
+        }
+        for r in 0..num_cols {
+            if r != rank && t[r][column].abs() > 1e-12 {
+                let factor = t[r][column];
+                for c in 0..num_rows {
+                    t[r][c] -= factor * t[rank][c];
+                }
+            }
+        }
+        rank += 1;
+        if rank == num_cols {
+            break;
+        }
+    }
+
+    let free_columns: Vec<usize> = (0..num_rows).filter(|&column| pivot_row_of_column[column].is_none()).collect();
+
+    let mut basis = Vec::new();
+    for &free_column in &free_columns {
+        let mut v = vec![0.0; num_rows];
+        v[free_column] = 1.0;
+        for column in 0..num_rows {
+            if let Some(pivot_row) = pivot_row_of_column[column] {
+                v[column] = -t[pivot_row][free_column];
+            }
+        }
+        basis.push(v);
+    }
+    basis
+}
+
+/// Low-pivot reduction: reduce `vector` against the established pivots in
+/// `pivots` (each a `(position, vector)` pair, one pivot per position),
+/// returning the reduced vector's own new pivot position if it didn't reduce
+/// to zero.
+fn reduce_against_pivots(mut vector: ChainVector, pivots: &HashMap<usize, ChainVector>) -> Option<(usize, ChainVector)> {
+    loop {
+        let lowest = vector.iter().position(|&x| x.abs() > 1e-9)?;
+        match pivots.get(&lowest) {
+            Some(pivot_vector) => {
+                let factor = vector[lowest] / pivot_vector[lowest];
+                for (v, p) in vector.iter_mut().zip(pivot_vector.iter()) {
+                    *v -= factor * p;
+                }
+            }
+            None => return Some((lowest, vector)),
+        }
+    }
+}
+
+/// Number of components of a braid's closure: the number of cycles of the
+/// permutation obtained by composing all of its crossings' adjacent
+/// transpositions in order (each crossing swaps whichever strands currently
+/// occupy positions `strand`/`strand + 1`), since the closure connects each
+/// bottom strand-end back to the top end at the same position.
+fn component_count(braid: &Braid) -> usize {
+    let n = braid.strands();
+    let mut position_to_start: Vec<usize> = (0..n).collect();
+    for crossing in braid.get_crossings() {
+        position_to_start.swap(crossing.strand, crossing.strand + 1);
+    }
+
+    let mut visited = vec![false; n];
+    let mut components = 0;
+    for start in 0..n {
+        if visited[start] {
+            continue;
+        }
+        components += 1;
+        let mut position = start;
+        while !visited[position] {
+            visited[position] = true;
+            position = position_to_start[position];
+        }
+    }
+    components
+}
+
+/// The Rasmussen `s`-invariant of a knot, as the average of its two Lee
+/// homology generators' quantum filtration levels
+///
+/// For a knot, Lee homology has total rank 2, entirely in homological degree
+/// 0. Its two generators' best-achievable quantum filtration levels
+/// (`s_min`, `s_max`, always 2 apart) are found by sorting generators of
+/// degree 0 by ascending `j`, then reducing first the boundary space (from
+/// degree -1, if any) and then the cycle space itself via low-pivot
+/// elimination in that order -- the standard persistence algorithm for
+/// filtered complexes. The two surviving cycle pivots' `j` values are
+/// `s_min` and `s_max`; `s = s_min + 1 = s_max - 1` is their average.
+///
+/// Lee's rank-`2^(#components)` theorem only gives rank 2 for a single
+/// component, so this errors up front for braids whose closure is a
+/// multi-component link instead of a knot.
+pub fn s_invariant(braid: &Braid) -> Result<i32, String> {
+    let components = component_count(braid);
+    if components != 1 {
+        return Err(format!(
+            "s_invariant is only defined for knots (single-component braid closures); this braid closes to a {}-component link",
+            components
+        ));
+    }
+
+    let (basis, differential) = build_complex(braid);
+
+    let mut by_degree: HashMap<i32, Vec<(&KhovanovGenerator, i32)>> = HashMap::new();
+    for (generator, i, j) in &basis {
+        by_degree.entry(*i).or_default().push((generator, *j));
+    }
+
+    let mut degree0: Vec<(&KhovanovGenerator, i32)> = by_degree.remove(&0).unwrap_or_default();
+    degree0.sort_by_key(|(generator, j)| (*j, generator.state.clone(), generator.labels.clone()));
+    let index0: HashMap<&KhovanovGenerator, usize> =
+        degree0.iter().enumerate().map(|(idx, (g, _))| (*g, idx)).collect();
+    let j_of = |idx: usize| degree0[idx].1;
+
+    let image_vector = |generator: &KhovanovGenerator, index: &HashMap<&KhovanovGenerator, usize>, dim: usize| -> ChainVector {
+        let mut row = vec![0.0; dim];
+        if let Some(targets) = differential.get(generator) {
+            for (target, coeff) in targets {
+                if let Some(&col) = index.get(target) {
+                    row[col] += *coeff as f64;
+                }
+            }
+        }
+        row
+    };
+
+    let target_index: HashMap<&KhovanovGenerator, usize> = by_degree
+        .get(&1)
+        .map(|v| v.iter().enumerate().map(|(idx, (g, _))| (*g, idx)).collect())
+        .unwrap_or_default();
+    let target_dim = by_degree.get(&1).map(|v| v.len()).unwrap_or(0);
+
+    let rows_d0: Vec<ChainVector> = degree0.iter().map(|(g, _)| image_vector(g, &target_index, target_dim)).collect();
+    let cycles = nullspace(&rows_d0, target_dim);
+
+    let boundary_vectors: Vec<ChainVector> = match by_degree.get(&-1) {
+        Some(prev) => prev.iter().map(|(g, _)| image_vector(g, &index0, degree0.len())).collect(),
+        None => Vec::new(),
+    };
+
+    let mut pivots: HashMap<usize, ChainVector> = HashMap::new();
+    for boundary in boundary_vectors {
+        if let Some((pos, reduced)) = reduce_against_pivots(boundary, &pivots) {
+            pivots.insert(pos, reduced);
+        }
+    }
+
+    let mut cycle_pivots: Vec<usize> = Vec::new();
+    for cycle in cycles {
+        if let Some((pos, reduced)) = reduce_against_pivots(cycle, &pivots) {
+            pivots.insert(pos, reduced);
+            cycle_pivots.push(pos);
+        }
+    }
+
+    cycle_pivots.sort_unstable();
+    assert_eq!(
+        cycle_pivots.len(),
+        2,
+        "Lee homology of a knot must have total rank 2; got {} surviving generators",
+        cycle_pivots.len()
+    );
+
+    let s_min = j_of(cycle_pivots[0]);
+    let s_max = j_of(cycle_pivots[1]);
+    Ok((s_min + s_max) / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_s_invariant_trefoil() {
+        let mut braid = Braid::new(2);
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+
+        assert_eq!(s_invariant(&braid).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_s_invariant_cinquefoil() {
+        let mut braid = Braid::new(2);
+        for _ in 0..5 {
+            braid.add_crossing(0, true).unwrap();
+        }
+
+        assert_eq!(s_invariant(&braid).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_s_invariant_figure_eight_is_slice() {
+        let mut braid = Braid::new(3);
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(1, false).unwrap();
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(1, false).unwrap();
+
+        assert_eq!(s_invariant(&braid).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_s_invariant_rejects_multi_component_link() {
+        // A single crossing on 3 strands has permutation (0 1)(2): two
+        // cycles, i.e. a 2-component link, not a knot.
+        let mut braid = Braid::new(3);
+        braid.add_crossing(0, true).unwrap();
+
+        assert!(s_invariant(&braid).is_err());
+    }
+
+    #[test]
+    fn test_lee_homology_total_rank_is_two_for_a_knot() {
+        let mut braid = Braid::new(2);
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+        braid.add_crossing(0, true).unwrap();
+
+        assert_eq!(lee_homology(&braid).total_rank(), 2);
+    }
+}
@@ -11,11 +11,19 @@ mod frb_generated; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be
 // - Statistical mechanics (Boltzmann distribution, entropy)
 
 pub mod adapters;
+pub mod curve;
+pub mod ops;
 pub mod polynomial;
 pub mod braid_group;
 pub mod knot_invariants;
+pub mod smith_normal_form;
+pub mod khovanov;
+pub mod lee_homology;
+pub mod knot_notation;
+pub mod knot_identification;
 pub mod knot_energy;
 pub mod knot_dynamics;
+pub mod knot_spline;
 pub mod knot_physics;
 pub mod api;
 
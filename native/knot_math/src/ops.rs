@@ -0,0 +1,105 @@
+// Deterministic math operations
+//
+// `knot_physics` leans on `exp`/`ln` for the partition function, Boltzmann
+// distribution, and entropy, and `knot_energy` leans on `sqrt`/`powi` for
+// curvature and norms. Both `std` float methods and hardware FPUs have
+// unspecified precision that can differ across platforms and Rust versions,
+// which matters when users diff knot energies or Boltzmann weights computed
+// on different machines.
+//
+// This module re-exports either the `std` float methods (default) or the
+// `libm` equivalents (behind the `libm` cargo feature) under one name, so
+// callers route through `ops::*` instead of calling the methods directly and
+// get bit-reproducible results when determinism is requested.
+
+#[cfg(not(feature = "libm"))]
+pub fn exp(x: f64) -> f64 {
+    x.exp()
+}
+
+#[cfg(feature = "libm")]
+pub fn exp(x: f64) -> f64 {
+    libm::exp(x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub fn ln(x: f64) -> f64 {
+    x.ln()
+}
+
+#[cfg(feature = "libm")]
+pub fn ln(x: f64) -> f64 {
+    libm::log(x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+
+#[cfg(feature = "libm")]
+pub fn sqrt(x: f64) -> f64 {
+    libm::sqrt(x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub fn powi(x: f64, n: i32) -> f64 {
+    x.powi(n)
+}
+
+/// Integer power via repeated multiplication (exponentiation by squaring)
+///
+/// `libm` has no integer-power primitive, so this shim stands in for
+/// `f64::powi` while keeping every multiplication bit-reproducible.
+#[cfg(feature = "libm")]
+pub fn powi(x: f64, n: i32) -> f64 {
+    if n == 0 {
+        return 1.0;
+    }
+
+    let negative = n < 0;
+    let mut remaining = n.unsigned_abs();
+    let mut base = x;
+    let mut result = 1.0;
+
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            result *= base;
+        }
+        base *= base;
+        remaining >>= 1;
+    }
+
+    if negative {
+        1.0 / result
+    } else {
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exp_matches_std() {
+        assert!((exp(1.0) - std::f64::consts::E).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_ln_matches_std() {
+        assert!((ln(std::f64::consts::E) - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_sqrt_matches_std() {
+        assert!((sqrt(4.0) - 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_powi_matches_std() {
+        assert!((powi(2.0, 10) - 1024.0).abs() < 1e-10);
+        assert!((powi(2.0, -2) - 0.25).abs() < 1e-10);
+        assert!((powi(5.0, 0) - 1.0).abs() < 1e-10);
+    }
+}
@@ -1,148 +1,367 @@
 // Polynomial mathematics for knot invariants
-// 
+//
 // Implements polynomial operations needed for Jones and Alexander polynomials
 // Uses rug::Float for arbitrary precision arithmetic
 
-use rug::{Float, ops::Pow};
+use rug::{Float, Integer, ops::Pow};
 use serde::{Deserialize, Serialize};
 
+/// Below this many terms, `Polynomial::multiply`'s schoolbook pass is faster
+/// than paying for an FFT's padding and bit-reversal overhead
+const FFT_MULTIPLY_THRESHOLD: usize = 32;
+
+/// Precision (in bits) the FFT-backed multiply path below runs at; matches
+/// the 256-bit precision the rest of `Polynomial` uses
+const FFT_PRECISION: u32 = 256;
+
+/// Minimal complex number at `Float` precision for the FFT-backed multiply
+/// path below (and for `EvaluationDomain`'s transforms further down); not
+/// worth pulling in an external complex-number crate for something this
+/// small. Uses explicit methods rather than operator overloads since
+/// `Float` isn't `Copy`. Public only so it can appear in `EvaluationDomain`'s
+/// public transform signatures - its fields stay crate-private.
+#[derive(Debug, Clone)]
+pub struct Complex {
+    re: Float,
+    im: Float,
+}
+
+impl Complex {
+    fn new(re: Float, im: Float) -> Self {
+        Complex { re, im }
+    }
+
+    fn zero(precision: u32) -> Self {
+        Complex::new(Float::with_val(precision, 0.0), Float::with_val(precision, 0.0))
+    }
+
+    fn add(&self, other: &Complex) -> Complex {
+        Complex::new(self.re.clone() + &other.re, self.im.clone() + &other.im)
+    }
+
+    fn sub(&self, other: &Complex) -> Complex {
+        Complex::new(self.re.clone() - &other.re, self.im.clone() - &other.im)
+    }
+
+    fn mul(&self, other: &Complex) -> Complex {
+        Complex::new(
+            self.re.clone() * &other.re - self.im.clone() * &other.im,
+            self.re.clone() * &other.im + self.im.clone() * &other.re,
+        )
+    }
+
+    /// Divide by `other`, via the usual conjugate trick: `self * conj(other)
+    /// / |other|^2`
+    fn div(&self, other: &Complex) -> Complex {
+        let denom = other.re.clone() * &other.re + other.im.clone() * &other.im;
+        let re = (self.re.clone() * &other.re + self.im.clone() * &other.im) / &denom;
+        let im = (self.im.clone() * &other.re - self.re.clone() * &other.im) / &denom;
+        Complex::new(re, im)
+    }
+
+    /// Raise to a non-negative integer power via repeated squaring
+    fn powu(&self, exponent: u32) -> Complex {
+        let precision = self.re.prec();
+        let mut result = Complex::new(Float::with_val(precision, 1.0), Float::with_val(precision, 0.0));
+        let mut base = self.clone();
+        let mut remaining = exponent;
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result = result.mul(&base);
+            }
+            base = base.mul(&base);
+            remaining >>= 1;
+        }
+        result
+    }
+}
+
+/// The `n`-th root of unity raised to the `k`-th power, at `precision` bits:
+/// `e^(i * sign * 2*pi*k/n)`, where `sign` is `-1` for the inverse transform
+/// and `+1` for the forward transform.
+fn root_of_unity(k: u32, n: u32, precision: u32, invert: bool) -> Complex {
+    let two_pi = Float::with_val(precision, rug::float::Constant::Pi) * 2;
+    let mut angle = two_pi * Float::with_val(precision, k) / Float::with_val(precision, n);
+    if invert {
+        angle = -angle;
+    }
+    Complex::new(angle.clone().cos(), angle.sin())
+}
+
+/// In-place iterative radix-2 Cooley-Tukey FFT (`a.len()` must be a power of
+/// two), at `Float` precision: bit-reversal permutation followed by
+/// `log2(n)` butterfly stages, each with its stage's twiddle factors
+/// generated once and then advanced by repeated multiplication rather than
+/// recomputed per butterfly.
+///
+/// `invert` runs the inverse transform (conjugate-twiddle direction,
+/// divided by `n`) instead of the forward transform.
+fn fft(a: &mut [Complex], precision: u32, invert: bool) {
+    let n = a.len();
+    if n <= 1 {
+        return;
+    }
+
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let wlen = root_of_unity(1, len as u32, precision, invert);
+        let mut i = 0;
+        while i < n {
+            let mut w = Complex::new(Float::with_val(precision, 1.0), Float::with_val(precision, 0.0));
+            for k in 0..len / 2 {
+                let u = a[i + k].clone();
+                let v = a[i + k + len / 2].mul(&w);
+                a[i + k] = u.add(&v);
+                a[i + k + len / 2] = u.sub(&v);
+                w = w.mul(&wlen);
+            }
+            i += len;
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        let n_float = Float::with_val(precision, n);
+        for x in a.iter_mut() {
+            x.re /= &n_float;
+            x.im /= &n_float;
+        }
+    }
+}
+
+/// Multiply two real coefficient vectors (lowest degree first) via FFT, at
+/// 256-bit `Float` precision throughout: pad both to the next power of two
+/// >= `a.len() + b.len() - 1`, transform, multiply pointwise,
+/// inverse-transform, and take the real parts
+fn fft_multiply(a: &[Float], b: &[Float]) -> Vec<Float> {
+    let precision = FFT_PRECISION;
+    let result_len = a.len() + b.len() - 1;
+    let mut n = 1;
+    while n < result_len {
+        n <<= 1;
+    }
+
+    let mut fa: Vec<Complex> = a
+        .iter()
+        .map(|x| Complex::new(x.clone(), Float::with_val(precision, 0.0)))
+        .collect();
+    fa.resize(n, Complex::zero(precision));
+    let mut fb: Vec<Complex> = b
+        .iter()
+        .map(|x| Complex::new(x.clone(), Float::with_val(precision, 0.0)))
+        .collect();
+    fb.resize(n, Complex::zero(precision));
+
+    fft(&mut fa, precision, false);
+    fft(&mut fb, precision, false);
+    for i in 0..n {
+        fa[i] = fa[i].mul(&fb[i]);
+    }
+    fft(&mut fa, precision, true);
+
+    fa.into_iter().take(result_len).map(|c| c.re).collect()
+}
+
 /// Polynomial with arbitrary precision coefficients
+///
+/// `coefficients[i]` is the coefficient of the term of exponent
+/// `min_exponent + i`, so `min_exponent` may be negative - this is the same
+/// shifted-index convention `LaurentPolynomial` below uses for `min_degree`.
+/// `new` defaults `min_exponent` to 0 for the ordinary non-negative-power
+/// case; `new_laurent` sets it explicitly. Unlike `LaurentPolynomial`, whose
+/// `Integer` coefficients are what actually backs the exact Jones and
+/// Alexander polynomials in `knot_invariants`, this type's `Float`
+/// coefficients suit numeric work (curve fitting, FFT-backed convolution)
+/// where only an approximation is needed or available.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Polynomial {
     coefficients: Vec<Float>,
+    min_exponent: i64,
 }
 
 impl Polynomial {
-    /// Create new polynomial from coefficients (lowest degree first)
+    /// Create new polynomial from coefficients (lowest degree first),
+    /// starting at exponent 0
     pub fn new(coefficients: Vec<f64>) -> Self {
+        Self::new_laurent(0, coefficients)
+    }
+
+    /// Create a new polynomial whose coefficients (lowest exponent first)
+    /// start at `min_exponent`, which may be negative
+    pub fn new_laurent(min_exponent: i64, coefficients: Vec<f64>) -> Self {
         let precision = 256; // 256 bits of precision
         Polynomial {
             coefficients: coefficients
                 .iter()
                 .map(|&c| Float::with_val(precision, c))
                 .collect(),
+            min_exponent,
         }
     }
 
-    /// Evaluate polynomial at point x
+    /// Evaluate polynomial at point x: sum of `c_i * x^(min_exponent+i)`,
+    /// with negative exponents evaluated as `1 / x^|exponent|`
     pub fn evaluate(&self, x: f64) -> f64 {
         let precision = 256;
         let x_float = Float::with_val(precision, x);
         let mut result = Float::with_val(precision, 0.0);
-        
+
         for (i, coeff) in self.coefficients.iter().enumerate() {
-            let x_power = if i == 0 {
+            let exponent = self.min_exponent + i as i64;
+            let x_power = if exponent == 0 {
                 Float::with_val(precision, 1.0)
+            } else if exponent > 0 {
+                Pow::pow(x_float.clone(), exponent as u32)
             } else {
-                Pow::pow(x_float.clone(), i as u32)
+                Float::with_val(precision, 1.0) / Pow::pow(x_float.clone(), (-exponent) as u32)
             };
             let term = coeff.clone() * x_power;
             result += term;
         }
-        
+
         result.to_f64()
     }
 
-    /// Get degree of polynomial
-    pub fn degree(&self) -> usize {
-        // Find highest non-zero coefficient
+    /// Lowest exponent with a coefficient slot (may be negative)
+    pub fn min_degree(&self) -> i64 {
+        self.min_exponent
+    }
+
+    /// Highest exponent with a non-zero coefficient (may be negative)
+    pub fn degree(&self) -> i64 {
         for (i, coeff) in self.coefficients.iter().enumerate().rev() {
             if coeff.to_f64().abs() > 1e-10 {
-                return i;
+                return self.min_exponent + i as i64;
             }
         }
-        0
+        self.min_exponent
     }
 
-    /// Get coefficient at given degree
-    pub fn coefficient(&self, degree: usize) -> f64 {
-        if degree >= self.coefficients.len() {
+    /// Get the coefficient of the term of the given exponent (may be
+    /// negative); zero outside the polynomial's stored range
+    pub fn coefficient(&self, exponent: i64) -> f64 {
+        let index = exponent - self.min_exponent;
+        if index < 0 || index as usize >= self.coefficients.len() {
             0.0
         } else {
-            self.coefficients[degree].to_f64()
+            self.coefficients[index as usize].to_f64()
         }
     }
 
-    /// Convert to Vec<f64> for FFI
+    /// Convert to Vec<f64> for FFI (lowest-exponent-first coefficients only;
+    /// callers needing `min_degree()` too must fetch it separately)
     pub fn to_vec(&self) -> Vec<f64> {
         self.coefficients.iter().map(|c| c.to_f64()).collect()
     }
 
-    /// Create from Vec<f64> (for FFI)
+    /// Create from Vec<f64> (for FFI), starting at exponent 0
     pub fn from_vec(v: Vec<f64>) -> Self {
         Self::new(v)
     }
 
-    /// Add two polynomials
+    /// Add two polynomials, aligning terms by exponent rather than by index
     pub fn add(&self, other: &Polynomial) -> Polynomial {
-        let max_len = self.coefficients.len().max(other.coefficients.len());
+        let min_exponent = self.min_exponent.min(other.min_exponent);
+        let max_exponent = self.degree().max(other.degree());
         let precision = 256;
         let mut result_coeffs = Vec::new();
-        
-        for i in 0..max_len {
-            let a = if i < self.coefficients.len() {
-                self.coefficients[i].clone()
-            } else {
-                Float::with_val(precision, 0.0)
-            };
-            let b = if i < other.coefficients.len() {
-                other.coefficients[i].clone()
-            } else {
-                Float::with_val(precision, 0.0)
-            };
+
+        let mut exponent = min_exponent;
+        while exponent <= max_exponent {
+            let a = Float::with_val(precision, self.coefficient(exponent));
+            let b = Float::with_val(precision, other.coefficient(exponent));
             result_coeffs.push(a + b);
+            exponent += 1;
         }
-        
+
         Polynomial {
             coefficients: result_coeffs,
+            min_exponent,
         }
     }
 
     /// Multiply two polynomials
+    ///
+    /// Schoolbook O(n*m) multiplication stays fast enough below
+    /// `FFT_MULTIPLY_THRESHOLD` terms; above it, dispatches to the
+    /// FFT-backed `multiply_fft` (see below), which is O(n log n) and runs
+    /// at the same 256-bit `Float` precision as every other method here, so
+    /// its result needs no rounding correction.
     pub fn multiply(&self, other: &Polynomial) -> Polynomial {
+        if self.coefficients.len() < FFT_MULTIPLY_THRESHOLD || other.coefficients.len() < FFT_MULTIPLY_THRESHOLD {
+            return self.multiply_naive(other);
+        }
+        self.multiply_fft(other)
+    }
+
+    /// Schoolbook O(n*m) polynomial multiplication, at full 256-bit
+    /// precision; the result's minimum exponent is the sum of the operands'
+    fn multiply_naive(&self, other: &Polynomial) -> Polynomial {
         let precision = 256;
         let result_len = self.coefficients.len() + other.coefficients.len() - 1;
         let mut result_coeffs = vec![Float::with_val(precision, 0.0); result_len];
-        
+
         for (i, a) in self.coefficients.iter().enumerate() {
             for (j, b) in other.coefficients.iter().enumerate() {
                 result_coeffs[i + j] += a.clone() * b.clone();
             }
         }
-        
+
         Polynomial {
             coefficients: result_coeffs,
+            min_exponent: self.min_exponent + other.min_exponent,
+        }
+    }
+
+    /// FFT-backed O(n log n) polynomial multiplication, at full 256-bit
+    /// `Float` precision (see `fft_multiply` above) rather than a round trip
+    /// through `f64` - the precision that makes this safe to use for large
+    /// polynomials without the coefficient-rounding workaround an `f64`-based
+    /// FFT would need. The result's minimum exponent is the sum of the
+    /// operands'.
+    pub fn multiply_fft(&self, other: &Polynomial) -> Polynomial {
+        Polynomial {
+            coefficients: fft_multiply(&self.coefficients, &other.coefficients),
+            min_exponent: self.min_exponent + other.min_exponent,
         }
     }
 
-    /// Calculate distance between two polynomials
+    /// Calculate distance between two polynomials, aligning terms by
+    /// exponent rather than by index
     /// Uses L2 norm: d = sqrt(Σ(a_i - b_i)²)
     pub fn distance(&self, other: &Polynomial) -> f64 {
-        let max_len = self.coefficients.len().max(other.coefficients.len());
+        let min_exponent = self.min_exponent.min(other.min_exponent);
+        let max_exponent = self.degree().max(other.degree());
         let precision = 256;
         let mut sum_sq = Float::with_val(precision, 0.0);
-        
-        for i in 0..max_len {
-            let a = if i < self.coefficients.len() {
-                self.coefficients[i].clone()
-            } else {
-                Float::with_val(precision, 0.0)
-            };
-            let b = if i < other.coefficients.len() {
-                other.coefficients[i].clone()
-            } else {
-                Float::with_val(precision, 0.0)
-            };
+
+        let mut exponent = min_exponent;
+        while exponent <= max_exponent {
+            let a = Float::with_val(precision, self.coefficient(exponent));
+            let b = Float::with_val(precision, other.coefficient(exponent));
             let diff = a - b;
             sum_sq += diff.clone() * diff;
+            exponent += 1;
         }
-        
+
         sum_sq.sqrt().to_f64()
     }
 
     /// Normalize polynomial (scale so leading coefficient is 1)
     pub fn normalize(&self) -> Polynomial {
-        let _precision = 256;
         if let Some(leading) = self.coefficients.last() {
             if leading.to_f64().abs() > 1e-10 {
                 let scale = leading.clone();
@@ -150,6 +369,7 @@ impl Polynomial {
                     coefficients: self.coefficients.iter()
                         .map(|c| c.clone() / scale.clone())
                         .collect(),
+                    min_exponent: self.min_exponent,
                 }
             } else {
                 self.clone()
@@ -158,6 +378,626 @@ impl Polynomial {
             self.clone()
         }
     }
+
+    /// Subtract `other` from `self`, aligning terms by exponent rather than by index
+    pub fn sub(&self, other: &Polynomial) -> Polynomial {
+        let min_exponent = self.min_exponent.min(other.min_exponent);
+        let max_exponent = self.degree().max(other.degree());
+        let precision = 256;
+        let mut result_coeffs = Vec::new();
+
+        let mut exponent = min_exponent;
+        while exponent <= max_exponent {
+            let a = Float::with_val(precision, self.coefficient(exponent));
+            let b = Float::with_val(precision, other.coefficient(exponent));
+            result_coeffs.push(a - b);
+            exponent += 1;
+        }
+
+        Polynomial {
+            coefficients: result_coeffs,
+            min_exponent,
+        }
+    }
+
+    /// True if every coefficient is within the usual `1e-10` tolerance of zero
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.iter().all(|c| c.to_f64().abs() < 1e-10)
+    }
+
+    /// Divide `self` by `divisor` via standard long division over `Float`
+    /// coefficients, returning `(quotient, remainder)`
+    ///
+    /// Repeatedly eliminates the remainder's leading term using `divisor`'s
+    /// leading term - the same degree-by-degree elimination
+    /// `LaurentPolynomial::div_exact` uses for exact integer division, but
+    /// tolerating (rather than requiring) a nonzero remainder, since `Float`
+    /// coefficients are rarely divisible exactly. Division is exact iff the
+    /// returned remainder's `is_zero()` is true; its `degree()` tells a
+    /// caller how far from exact it is otherwise.
+    pub fn divide(&self, divisor: &Polynomial) -> Result<(Polynomial, Polynomial), String> {
+        if divisor.is_zero() {
+            return Err("Cannot divide by the zero polynomial".to_string());
+        }
+        let divisor_degree = divisor.degree();
+        let divisor_lead = divisor.coefficient(divisor_degree);
+
+        let mut remainder = self.clone();
+        let mut quotient_terms: Vec<(i64, f64)> = Vec::new();
+
+        while !remainder.is_zero() && remainder.degree() >= divisor_degree {
+            let remainder_degree = remainder.degree();
+            let lead = remainder.coefficient(remainder_degree);
+            let term_degree = remainder_degree - divisor_degree;
+            let term_coeff = lead / divisor_lead;
+            let term = Polynomial::new_laurent(term_degree, vec![term_coeff]);
+            remainder = remainder.sub(&term.multiply(divisor));
+            quotient_terms.push((term_degree, term_coeff));
+        }
+
+        if quotient_terms.is_empty() {
+            return Ok((Polynomial::new(vec![0.0]), remainder));
+        }
+
+        let min_degree = quotient_terms.iter().map(|(d, _)| *d).min().unwrap();
+        let max_degree = quotient_terms.iter().map(|(d, _)| *d).max().unwrap();
+        let len = (max_degree - min_degree + 1) as usize;
+        let mut coefficients = vec![0.0; len];
+        for (degree, coeff) in quotient_terms {
+            coefficients[(degree - min_degree) as usize] += coeff;
+        }
+
+        Ok((Polynomial::new_laurent(min_degree, coefficients), remainder))
+    }
+
+    /// Greatest common divisor of two polynomials, via the Euclidean
+    /// algorithm on `divide`'s remainders
+    ///
+    /// Alexander polynomials are only defined up to multiplication by a
+    /// unit (`±t^k`), so comparing two candidate invariants by equality is
+    /// too strict; reducing both to their GCD (and checking the result
+    /// against one of them via `divide`) is the natural equivalence test.
+    pub fn gcd(&self, other: &Polynomial) -> Result<Polynomial, String> {
+        let mut a = self.clone();
+        let mut b = other.clone();
+
+        while !b.is_zero() {
+            let (_, remainder) = a.divide(&b)?;
+            a = b;
+            b = remainder;
+        }
+
+        Ok(a)
+    }
+
+    /// Build a polynomial passing through `points` (distinct `(x, y)`
+    /// pairs) by Lagrange interpolation
+    ///
+    /// Computes each point's barycentric weight `w_i = 1 / prod_{j!=i}
+    /// (x_i - x_j)` once, then sums `y_i * w_i * prod_{j!=i} (x - x_j)` -
+    /// the standard barycentric form, just expanded into coefficients
+    /// (via repeated `multiply`/`add`) rather than left as a point
+    /// evaluator. Useful for reconstructing a knot invariant polynomial
+    /// from sampled `evaluate` values, e.g. after round-tripping through an
+    /// `EvaluationDomain` below.
+    pub fn interpolate(points: &[(f64, f64)]) -> Result<Polynomial, String> {
+        if points.is_empty() {
+            return Err("Cannot interpolate through zero points".to_string());
+        }
+        let precision = 256;
+        let n = points.len();
+
+        let mut weights = Vec::with_capacity(n);
+        for i in 0..n {
+            let mut denom = Float::with_val(precision, 1.0);
+            for j in 0..n {
+                if i == j {
+                    continue;
+                }
+                let diff = Float::with_val(precision, points[i].0 - points[j].0);
+                if diff.to_f64().abs() < 1e-10 {
+                    return Err(format!("Duplicate x value {} in interpolation points", points[i].0));
+                }
+                denom *= diff;
+            }
+            weights.push(Float::with_val(precision, 1.0) / denom);
+        }
+
+        let mut result = Polynomial::new(vec![0.0]);
+        for i in 0..n {
+            let mut basis = Polynomial::new(vec![1.0]);
+            for j in 0..n {
+                if i == j {
+                    continue;
+                }
+                basis = basis.multiply(&Polynomial::new(vec![-points[j].0, 1.0]));
+            }
+            let scale = weights[i].to_f64() * points[i].1;
+            let term_coeffs: Vec<f64> = basis.to_vec().iter().map(|c| c * scale).collect();
+            result = result.add(&Polynomial::new(term_coeffs));
+        }
+
+        Ok(result)
+    }
+
+    /// Evaluate at a complex point via Horner's method, without the
+    /// cancellation error that comparing two `f64` evaluations of equal
+    /// polynomials can suffer from
+    ///
+    /// Factors out `x^min_exponent` (so the remaining ordinary polynomial's
+    /// coefficients run from its own lowest term up) and runs Horner from
+    /// the highest coefficient down, then multiplies or divides by the
+    /// factored-out power (`evaluate` instead computes each term's power
+    /// separately).
+    pub fn evaluate_complex(&self, x: &Complex) -> Complex {
+        let precision = FFT_PRECISION;
+        let mut result = Complex::zero(precision);
+        for coeff in self.coefficients.iter().rev() {
+            let coeff = Complex::new(coeff.clone(), Float::with_val(precision, 0.0));
+            result = result.mul(x).add(&coeff);
+        }
+        if self.min_exponent == 0 {
+            result
+        } else if self.min_exponent > 0 {
+            result.mul(&x.powu(self.min_exponent as u32))
+        } else {
+            result.div(&x.powu((-self.min_exponent) as u32))
+        }
+    }
+
+    /// Evaluate at the `k`-th `n`-th root of unity (`e^(2*pi*i*k/n)`) directly,
+    /// for probing equality between two invariants without ever picking a
+    /// real sample point that might coincide with a root
+    pub fn evaluate_at_root_of_unity(&self, k: u32, n: u32) -> Complex {
+        let root = root_of_unity(k, n, FFT_PRECISION, false);
+        self.evaluate_complex(&root)
+    }
+}
+
+/// Evaluation-point ("Lagrange") representation of a polynomial over the
+/// `size()`-th complex roots of unity at 256-bit precision. The domain holds
+/// only its size and precision; each `coeffs_to_evals`/`evals_to_coeffs`
+/// call runs `fft`, which generates its roots of unity afresh, so no roots
+/// are cached between calls
+///
+/// Mirrors the `Coeff` vs `LagrangeCoeff` dual representation in halo2's
+/// `poly` module: once several polynomials sharing a domain are in
+/// evaluation form, `multiply`/`add` become pointwise (and cheap) instead of
+/// a fresh convolution or exponent-alignment pass every time, and only a
+/// single inverse transform is needed to recover coefficients at the end.
+pub struct EvaluationDomain {
+    size: usize,
+    precision: u32,
+}
+
+impl EvaluationDomain {
+    /// Build a domain of at least `min_size` points, rounded up to the next
+    /// power of two (the underlying transform is radix-2)
+    pub fn new(min_size: usize) -> Self {
+        let mut size = 1;
+        while size < min_size.max(1) {
+            size <<= 1;
+        }
+        EvaluationDomain { size, precision: FFT_PRECISION }
+    }
+
+    /// Number of points in this domain
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Transform coefficients (lowest degree first) into this domain's
+    /// point-value representation, zero-padded up to `size()`
+    pub fn coeffs_to_evals(&self, coeffs: &[Float]) -> Vec<Complex> {
+        let mut a: Vec<Complex> = coeffs
+            .iter()
+            .map(|c| Complex::new(c.clone(), Float::with_val(self.precision, 0.0)))
+            .collect();
+        a.resize(self.size, Complex::zero(self.precision));
+        fft(&mut a, self.precision, false);
+        a
+    }
+
+    /// Inverse-transform point values back into dense coefficients (lowest
+    /// degree first, length `size()`); trailing near-zero coefficients are
+    /// left in place, matching `Polynomial`'s own dense representation
+    pub fn evals_to_coeffs(&self, evals: &[Complex]) -> Vec<Float> {
+        let mut a = evals.to_vec();
+        a.resize(self.size, Complex::zero(self.precision));
+        fft(&mut a, self.precision, true);
+        a.into_iter().map(|c| c.re).collect()
+    }
+}
+
+/// A polynomial with `Complex`-valued coefficients: the "extension field"
+/// companion to `Polynomial` for algebra that needs to stay closed under
+/// complex evaluation, following powdr's move-to-an-extension-field idea -
+/// combining two invariants' complex evaluations (e.g. at a root of unity)
+/// shouldn't have to collapse back through `Polynomial`'s real coefficients
+/// first. `coefficients[i]` is the coefficient of the term of exponent
+/// `min_exponent + i`, the same shifted-index convention `Polynomial` uses.
+#[derive(Debug, Clone)]
+pub struct ComplexPolynomial {
+    coefficients: Vec<Complex>,
+    min_exponent: i64,
+}
+
+impl ComplexPolynomial {
+    /// Build directly from complex coefficients (lowest exponent first)
+    pub fn new(min_exponent: i64, coefficients: Vec<Complex>) -> Self {
+        ComplexPolynomial { coefficients, min_exponent }
+    }
+
+    /// Lift a real-coefficient `Polynomial` into the complex domain (every
+    /// coefficient gets a zero imaginary part)
+    pub fn from_real(poly: &Polynomial) -> Self {
+        let precision = FFT_PRECISION;
+        let coefficients = poly
+            .coefficients
+            .iter()
+            .map(|c| Complex::new(c.clone(), Float::with_val(precision, 0.0)))
+            .collect();
+        ComplexPolynomial::new(poly.min_exponent, coefficients)
+    }
+
+    fn coefficient(&self, exponent: i64) -> Complex {
+        let index = exponent - self.min_exponent;
+        if index < 0 || index as usize >= self.coefficients.len() {
+            Complex::zero(FFT_PRECISION)
+        } else {
+            self.coefficients[index as usize].clone()
+        }
+    }
+
+    /// Evaluate at a complex point via Horner's method, using the same
+    /// `x^min_exponent` factoring `Polynomial::evaluate_complex` uses
+    pub fn evaluate(&self, x: &Complex) -> Complex {
+        let mut result = Complex::zero(FFT_PRECISION);
+        for coeff in self.coefficients.iter().rev() {
+            result = result.mul(x).add(coeff);
+        }
+        if self.min_exponent == 0 {
+            result
+        } else if self.min_exponent > 0 {
+            result.mul(&x.powu(self.min_exponent as u32))
+        } else {
+            result.div(&x.powu((-self.min_exponent) as u32))
+        }
+    }
+
+    /// Add two complex-coefficient polynomials, aligning by exponent over
+    /// their union range (the same approach `Polynomial::add` uses)
+    pub fn add(&self, other: &ComplexPolynomial) -> ComplexPolynomial {
+        let min_exponent = self.min_exponent.min(other.min_exponent);
+        let max_exponent = (self.min_exponent + self.coefficients.len() as i64 - 1)
+            .max(other.min_exponent + other.coefficients.len() as i64 - 1);
+        let mut coefficients = Vec::new();
+        let mut exponent = min_exponent;
+        while exponent <= max_exponent {
+            coefficients.push(self.coefficient(exponent).add(&other.coefficient(exponent)));
+            exponent += 1;
+        }
+        ComplexPolynomial::new(min_exponent, coefficients)
+    }
+
+    /// Multiply two complex-coefficient polynomials via schoolbook
+    /// convolution (below `Polynomial::multiply`'s FFT threshold in
+    /// practice, since this type backs small per-invariant comparisons
+    /// rather than bulk polynomial arithmetic)
+    pub fn multiply(&self, other: &ComplexPolynomial) -> ComplexPolynomial {
+        let mut coefficients =
+            vec![Complex::zero(FFT_PRECISION); self.coefficients.len() + other.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in other.coefficients.iter().enumerate() {
+                coefficients[i + j] = coefficients[i + j].add(&a.mul(b));
+            }
+        }
+        ComplexPolynomial::new(self.min_exponent + other.min_exponent, coefficients)
+    }
+}
+
+/// Laurent polynomial with exact integer coefficients
+///
+/// `Polynomial` (above) can represent negative exponents too via its own
+/// `min_exponent`, but its `Float` coefficients are only ever an
+/// approximation - not suitable for the exact integer coefficients real
+/// Jones and Alexander polynomials require (e.g. the trefoil's
+/// `-t^-4 + t^-3 + t^-1`). `coefficients[i]` here is the exact integer
+/// coefficient of the term of exponent `min_degree + i`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LaurentPolynomial {
+    coefficients: Vec<Integer>,
+    min_degree: i64,
+}
+
+impl LaurentPolynomial {
+    /// Create a new Laurent polynomial from its minimum exponent and
+    /// coefficients (lowest exponent first)
+    pub fn new(min_degree: i64, coefficients: Vec<i64>) -> Self {
+        LaurentPolynomial {
+            coefficients: coefficients.into_iter().map(Integer::from).collect(),
+            min_degree,
+        }
+        .trimmed()
+    }
+
+    /// Drop leading/trailing zero coefficients, recording the true span
+    fn trimmed(self) -> Self {
+        if self.coefficients.is_empty() {
+            return self;
+        }
+
+        let first_nonzero = self.coefficients.iter().position(|c| *c != 0);
+        let first_nonzero = match first_nonzero {
+            Some(i) => i,
+            None => return LaurentPolynomial { coefficients: vec![Integer::from(0)], min_degree: 0 },
+        };
+        let last_nonzero = self.coefficients.iter().rposition(|c| *c != 0).unwrap();
+
+        LaurentPolynomial {
+            min_degree: self.min_degree + first_nonzero as i64,
+            coefficients: self.coefficients[first_nonzero..=last_nonzero].to_vec(),
+        }
+    }
+
+    /// Lowest exponent with a (potentially) nonzero coefficient
+    pub fn min_degree(&self) -> i64 {
+        self.min_degree
+    }
+
+    /// Highest exponent with a (potentially) nonzero coefficient
+    pub fn max_degree(&self) -> i64 {
+        self.min_degree + self.coefficients.len() as i64 - 1
+    }
+
+    /// Coefficient of the term with the given exponent
+    pub fn coefficient(&self, exponent: i64) -> Integer {
+        if exponent < self.min_degree || exponent > self.max_degree() {
+            Integer::from(0)
+        } else {
+            self.coefficients[(exponent - self.min_degree) as usize].clone()
+        }
+    }
+
+    /// Evaluate the polynomial at a real point x, handling negative exponents via 1/x^|e|
+    pub fn evaluate(&self, x: f64) -> f64 {
+        let mut result = 0.0;
+        for (i, coeff) in self.coefficients.iter().enumerate() {
+            let exponent = self.min_degree + i as i64;
+            let term = coeff.to_f64() * x.powi(exponent as i32);
+            result += term;
+        }
+        result
+    }
+
+    /// Add two Laurent polynomials, aligning by exponent rather than index
+    pub fn add(&self, other: &LaurentPolynomial) -> LaurentPolynomial {
+        let min_degree = self.min_degree.min(other.min_degree);
+        let max_degree = self.max_degree().max(other.max_degree());
+        let len = (max_degree - min_degree + 1) as usize;
+        let mut coefficients = vec![Integer::from(0); len];
+
+        for exponent in min_degree..=max_degree {
+            let idx = (exponent - min_degree) as usize;
+            coefficients[idx] = self.coefficient(exponent) + other.coefficient(exponent);
+        }
+
+        LaurentPolynomial { coefficients, min_degree }.trimmed()
+    }
+
+    /// Subtract `other` from `self`, aligning by exponent
+    pub fn sub(&self, other: &LaurentPolynomial) -> LaurentPolynomial {
+        let min_degree = self.min_degree.min(other.min_degree);
+        let max_degree = self.max_degree().max(other.max_degree());
+        let len = (max_degree - min_degree + 1) as usize;
+        let mut coefficients = vec![Integer::from(0); len];
+
+        for exponent in min_degree..=max_degree {
+            let idx = (exponent - min_degree) as usize;
+            coefficients[idx] = self.coefficient(exponent) - other.coefficient(exponent);
+        }
+
+        LaurentPolynomial { coefficients, min_degree }.trimmed()
+    }
+
+    /// Multiply two Laurent polynomials; the result's min_degree is the sum of the two
+    pub fn mul(&self, other: &LaurentPolynomial) -> LaurentPolynomial {
+        let len = self.coefficients.len() + other.coefficients.len() - 1;
+        let mut coefficients = vec![Integer::from(0); len];
+
+        for (i, a) in self.coefficients.iter().enumerate() {
+            if *a == 0 {
+                continue;
+            }
+            for (j, b) in other.coefficients.iter().enumerate() {
+                coefficients[i + j] += a.clone() * b.clone();
+            }
+        }
+
+        LaurentPolynomial {
+            coefficients,
+            min_degree: self.min_degree + other.min_degree,
+        }
+        .trimmed()
+    }
+
+    /// Substitute the variable by a rational power of a new variable, e.g. `A -> t^(-1/4)`
+    ///
+    /// Every exponent `e` of `self` becomes `e * power_num / power_den` in the
+    /// result; this is exact only when that division is integral for every
+    /// exponent with a nonzero coefficient (as is guaranteed for the Kauffman
+    /// bracket's `A -> t^(-1/4)` normalization once reduced to the Jones
+    /// polynomial), otherwise an error is returned.
+    pub fn substitute(&self, power_num: i64, power_den: i64) -> Result<LaurentPolynomial, String> {
+        if power_den == 0 {
+            return Err("Substitution denominator cannot be zero".to_string());
+        }
+
+        let mut by_exponent: std::collections::BTreeMap<i64, Integer> = std::collections::BTreeMap::new();
+        for (i, coeff) in self.coefficients.iter().enumerate() {
+            if *coeff == 0 {
+                continue;
+            }
+            let exponent = self.min_degree + i as i64;
+            let numerator = exponent * power_num;
+            if numerator % power_den != 0 {
+                return Err(format!(
+                    "Exponent {} is not exactly representable after substituting power {}/{}",
+                    exponent, power_num, power_den
+                ));
+            }
+            let new_exponent = numerator / power_den;
+            *by_exponent.entry(new_exponent).or_insert_with(|| Integer::from(0)) += coeff.clone();
+        }
+
+        if by_exponent.is_empty() {
+            return Ok(LaurentPolynomial::new(0, vec![0]));
+        }
+
+        let min_degree = *by_exponent.keys().next().unwrap();
+        let max_degree = *by_exponent.keys().next_back().unwrap();
+        let len = (max_degree - min_degree + 1) as usize;
+        let mut coefficients = vec![Integer::from(0); len];
+        for (exponent, coeff) in by_exponent {
+            coefficients[(exponent - min_degree) as usize] = coeff;
+        }
+
+        Ok(LaurentPolynomial { coefficients, min_degree }.trimmed())
+    }
+
+    /// Substitute like `substitute`, but round each resulting exponent to
+    /// the nearest integer instead of rejecting non-integral results
+    ///
+    /// Used as a fallback for closures with more than one component, where
+    /// the Kauffman bracket's `A -> t^(-1/4)` substitution isn't guaranteed
+    /// to land on integral exponents.
+    pub fn substitute_rounded(&self, power_num: i64, power_den: i64) -> LaurentPolynomial {
+        let mut by_exponent: std::collections::BTreeMap<i64, Integer> = std::collections::BTreeMap::new();
+        for (i, coeff) in self.coefficients.iter().enumerate() {
+            if *coeff == 0 {
+                continue;
+            }
+            let exponent = self.min_degree + i as i64;
+            let new_exponent = ((exponent * power_num) as f64 / power_den as f64).round() as i64;
+            *by_exponent.entry(new_exponent).or_insert_with(|| Integer::from(0)) += coeff.clone();
+        }
+
+        if by_exponent.is_empty() {
+            return LaurentPolynomial::new(0, vec![0]);
+        }
+
+        let min_degree = *by_exponent.keys().next().unwrap();
+        let max_degree = *by_exponent.keys().next_back().unwrap();
+        let len = (max_degree - min_degree + 1) as usize;
+        let mut coefficients = vec![Integer::from(0); len];
+        for (exponent, coeff) in by_exponent {
+            coefficients[(exponent - min_degree) as usize] = coeff;
+        }
+
+        LaurentPolynomial { coefficients, min_degree }.trimmed()
+    }
+
+    /// True if every coefficient is zero
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.iter().all(|c| *c == 0)
+    }
+
+    /// Divide `self` by `divisor`, requiring the division to be exact
+    /// (zero remainder)
+    ///
+    /// Used by fraction-free Bareiss elimination, where the algorithm
+    /// guarantees every intermediate division is exact; returns an error
+    /// if that guarantee doesn't hold (e.g. mismatched inputs).
+    pub fn div_exact(&self, divisor: &LaurentPolynomial) -> Result<LaurentPolynomial, String> {
+        if divisor.is_zero() {
+            return Err("Cannot divide by the zero polynomial".to_string());
+        }
+        if self.is_zero() {
+            return Ok(LaurentPolynomial::new(0, vec![0]));
+        }
+
+        let mut remainder = self.clone();
+        let divisor_lead = divisor.coefficient(divisor.max_degree());
+        let mut quotient_terms: Vec<(i64, Integer)> = Vec::new();
+
+        while !remainder.is_zero() && remainder.max_degree() >= divisor.max_degree() {
+            let lead = remainder.coefficient(remainder.max_degree());
+            if Integer::from(&lead % &divisor_lead) != 0 {
+                return Err(format!(
+                    "Division of {} by {} is not exact",
+                    lead, divisor_lead
+                ));
+            }
+            let term_coeff = Integer::from(&lead / &divisor_lead);
+            let term_degree = remainder.max_degree() - divisor.max_degree();
+            let term = LaurentPolynomial::new(term_degree, vec![term_coeff.to_i64().unwrap_or(0)]);
+            remainder = remainder.sub(&term.mul(divisor));
+            quotient_terms.push((term_degree, term_coeff));
+        }
+
+        if !remainder.is_zero() {
+            return Err("Division leaves a nonzero remainder".to_string());
+        }
+
+        if quotient_terms.is_empty() {
+            return Ok(LaurentPolynomial::new(0, vec![0]));
+        }
+
+        let min_degree = quotient_terms.iter().map(|(d, _)| *d).min().unwrap();
+        let max_degree = quotient_terms.iter().map(|(d, _)| *d).max().unwrap();
+        let len = (max_degree - min_degree + 1) as usize;
+        let mut coefficients = vec![Integer::from(0); len];
+        for (degree, coeff) in quotient_terms {
+            coefficients[(degree - min_degree) as usize] = coeff;
+        }
+
+        Ok(LaurentPolynomial { coefficients, min_degree }.trimmed())
+    }
+
+    /// Dense coefficients (lowest exponent first) as f64, for interop with
+    /// code that still expects a plain coefficient vector
+    pub fn coefficients_f64(&self) -> Vec<f64> {
+        self.coefficients.iter().map(|c| c.to_f64()).collect()
+    }
+
+    /// Negate every coefficient
+    pub fn neg(&self) -> LaurentPolynomial {
+        LaurentPolynomial {
+            coefficients: self.coefficients.iter().map(|c| -c.clone()).collect(),
+            min_degree: self.min_degree,
+        }
+    }
+
+    /// Multiply by `t^shift`, i.e. shift every exponent by `shift`
+    pub fn shift(&self, shift: i64) -> LaurentPolynomial {
+        LaurentPolynomial {
+            coefficients: self.coefficients.clone(),
+            min_degree: self.min_degree + shift,
+        }
+    }
+
+    /// Dense `(exponent, coefficient)` pairs, lowest exponent first and
+    /// exact (no float rounding) -- the FFI-friendly counterpart to
+    /// `coefficients_f64` that preserves negative exponents and exactness
+    pub fn exponents_and_coefficients(&self) -> (Vec<i32>, Vec<i64>) {
+        let exponents = (0..self.coefficients.len()).map(|i| (self.min_degree + i as i64) as i32).collect();
+        let coefficients = self.coefficients.iter().map(|c| c.to_i64().unwrap_or(0)).collect();
+        (exponents, coefficients)
+    }
+
+    /// L2 distance between two Laurent polynomials over their matching exponent range
+    pub fn distance(&self, other: &LaurentPolynomial) -> f64 {
+        let min_degree = self.min_degree.min(other.min_degree);
+        let max_degree = self.max_degree().max(other.max_degree());
+
+        let mut sum_sq = 0.0;
+        for exponent in min_degree..=max_degree {
+            let diff = self.coefficient(exponent).to_f64() - other.coefficient(exponent).to_f64();
+            sum_sq += diff * diff;
+        }
+
+        sum_sq.sqrt()
+    }
 }
 
 #[cfg(test)]
@@ -187,7 +1027,7 @@ mod tests {
         let p1 = Polynomial::new(vec![1.0, 2.0]);
         let p2 = Polynomial::new(vec![3.0, 4.0, 5.0]);
         let sum = p1.add(&p2);
-        
+
         // Should be: 1+3, 2+4, 0+5 = [4, 6, 5]
         assert!((sum.coefficient(0) - 4.0).abs() < 1e-10);
         assert!((sum.coefficient(1) - 6.0).abs() < 1e-10);
@@ -199,20 +1039,444 @@ mod tests {
         let p1 = Polynomial::new(vec![1.0, 2.0]);  // 1 + 2x
         let p2 = Polynomial::new(vec![3.0, 4.0]);  // 3 + 4x
         let product = p1.multiply(&p2);
-        
+
         // Should be: (1+2x)(3+4x) = 3 + 10x + 8x²
         assert!((product.coefficient(0) - 3.0).abs() < 1e-10);
         assert!((product.coefficient(1) - 10.0).abs() < 1e-10);
         assert!((product.coefficient(2) - 8.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_new_laurent_evaluates_negative_exponents() {
+        // x^-1 + 2 + 3x, i.e. min_exponent -1
+        let poly = Polynomial::new_laurent(-1, vec![1.0, 2.0, 3.0]);
+        assert_eq!(poly.min_degree(), -1);
+        assert_eq!(poly.degree(), 1);
+
+        // At x = 2: 1/2 + 2 + 6 = 8.5
+        assert!((poly.evaluate(2.0) - 8.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_new_laurent_coefficient_lookup() {
+        let poly = Polynomial::new_laurent(-2, vec![5.0, 6.0, 7.0]);
+        assert!((poly.coefficient(-2) - 5.0).abs() < 1e-10);
+        assert!((poly.coefficient(-1) - 6.0).abs() < 1e-10);
+        assert!((poly.coefficient(0) - 7.0).abs() < 1e-10);
+        // Outside the stored range is zero, not a panic
+        assert_eq!(poly.coefficient(-5), 0.0);
+        assert_eq!(poly.coefficient(5), 0.0);
+    }
+
+    #[test]
+    fn test_laurent_polynomial_add_aligns_by_exponent() {
+        // x^-1 + 2x^0   and   3x^0 + 4x^1, summed term-by-term on exponent
+        let p1 = Polynomial::new_laurent(-1, vec![1.0, 2.0]);
+        let p2 = Polynomial::new_laurent(0, vec![3.0, 4.0]);
+        let sum = p1.add(&p2);
+
+        assert_eq!(sum.min_degree(), -1);
+        assert!((sum.coefficient(-1) - 1.0).abs() < 1e-10);
+        assert!((sum.coefficient(0) - 5.0).abs() < 1e-10); // 2 + 3
+        assert!((sum.coefficient(1) - 4.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_laurent_polynomial_multiply_sums_min_exponents() {
+        // x^-1 * x^-2 should start at exponent -3
+        let p1 = Polynomial::new_laurent(-1, vec![1.0, 1.0]);
+        let p2 = Polynomial::new_laurent(-2, vec![1.0, 1.0]);
+        let product = p1.multiply(&p2);
+
+        assert_eq!(product.min_degree(), -3);
+        // (1 + x)(1 + x) = 1 + 2x + x^2, shifted to start at -3
+        assert!((product.coefficient(-3) - 1.0).abs() < 1e-10);
+        assert!((product.coefficient(-2) - 2.0).abs() < 1e-10);
+        assert!((product.coefficient(-1) - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_laurent_polynomial_distance_aligns_by_exponent() {
+        let p1 = Polynomial::new_laurent(-1, vec![1.0, 2.0]);
+        let p2 = Polynomial::new_laurent(0, vec![2.0, 2.0]);
+        // p1: x^-1 + 2; p2: 2 + 2x
+        // aligned: exponent -1: 1 vs 0 (diff 1); exponent 0: 2 vs 2 (diff 0);
+        // exponent 1: 0 vs 2 (diff 2) -> sqrt(1 + 0 + 4) = sqrt(5)
+        let dist = p1.distance(&p2);
+        assert!((dist - 5.0_f64.sqrt()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_multiply_dispatches_to_fft_above_threshold() {
+        // 40 terms each, comfortably above FFT_MULTIPLY_THRESHOLD, so
+        // `multiply` dispatches to `multiply_fft` here.
+        let a_coeffs: Vec<f64> = (0..40).map(|i| ((i * 7 + 3) % 11) as f64 - 5.0).collect();
+        let b_coeffs: Vec<f64> = (0..40).map(|i| ((i * 13 + 5) % 9) as f64 - 4.0).collect();
+
+        let a = Polynomial::new(a_coeffs);
+        let b = Polynomial::new(b_coeffs);
+
+        let dispatched_product = a.multiply(&b);
+        let fft_product = a.multiply_fft(&b);
+
+        let result_len: i64 = 40 + 40 - 1;
+        for i in 0..result_len {
+            assert!(
+                (dispatched_product.coefficient(i) - fft_product.coefficient(i)).abs() < 1e-10,
+                "degree {}: multiply() {} vs multiply_fft() {}",
+                i,
+                dispatched_product.coefficient(i),
+                fft_product.coefficient(i)
+            );
+        }
+    }
+
+    #[test]
+    fn test_multiply_fft_agrees_with_schoolbook_on_integer_polynomials() {
+        // 40 terms each, comfortably above FFT_MULTIPLY_THRESHOLD.
+        let a_coeffs: Vec<f64> = (0..40).map(|i| ((i * 7 + 3) % 11) as f64 - 5.0).collect();
+        let b_coeffs: Vec<f64> = (0..40).map(|i| ((i * 13 + 5) % 9) as f64 - 4.0).collect();
+
+        let a = Polynomial::new(a_coeffs);
+        let b = Polynomial::new(b_coeffs);
+
+        let fft_product = a.multiply_fft(&b);
+        let schoolbook_product = a.multiply_naive(&b);
+
+        let result_len: i64 = 40 + 40 - 1;
+        for i in 0..result_len {
+            assert!(
+                (fft_product.coefficient(i) - schoolbook_product.coefficient(i)).abs() < 1e-10,
+                "mismatch at degree {}: fft {} vs schoolbook {}",
+                i,
+                fft_product.coefficient(i),
+                schoolbook_product.coefficient(i)
+            );
+        }
+    }
+
+    #[test]
+    fn test_multiply_fft_round_trips_through_evaluate() {
+        let a_coeffs: Vec<f64> = (0..40).map(|i| ((i * 3 + 1) % 7) as f64 - 3.0).collect();
+        let b_coeffs: Vec<f64> = (0..35).map(|i| ((i * 5 + 2) % 6) as f64 - 2.0).collect();
+
+        let a = Polynomial::new(a_coeffs);
+        let b = Polynomial::new(b_coeffs);
+        let product = a.multiply_fft(&b);
+
+        // product(x) should equal a(x) * b(x) at any sample point. At high
+        // degree and |x| > 1 the values themselves get astronomically
+        // large, so compare relative rather than absolute error - both
+        // sides only lose precision in the final 256-bit-to-f64 cast.
+        for &x in &[0.5, 1.0, 1.7, -2.3] {
+            let expected = a.evaluate(x) * b.evaluate(x);
+            let actual = product.evaluate(x);
+            let relative_error = if expected.abs() > 1e-9 {
+                (actual - expected).abs() / expected.abs()
+            } else {
+                (actual - expected).abs()
+            };
+            assert!(
+                relative_error < 1e-9,
+                "mismatch at x={}: product {} vs a(x)*b(x) {}",
+                x,
+                actual,
+                expected
+            );
+        }
+    }
+
     #[test]
     fn test_polynomial_distance() {
         let p1 = Polynomial::new(vec![1.0, 2.0, 3.0]);
         let p2 = Polynomial::new(vec![1.0, 2.0, 4.0]);
         let dist = p1.distance(&p2);
-        
+
         // Distance should be |3-4| = 1
         assert!((dist - 1.0).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_divide_exact_divisibility() {
+        // (x^2 - 1) / (x - 1) = x + 1, remainder 0
+        let dividend = Polynomial::new(vec![-1.0, 0.0, 1.0]);
+        let divisor = Polynomial::new(vec![-1.0, 1.0]);
+        let (quotient, remainder) = dividend.divide(&divisor).unwrap();
+
+        assert!((quotient.coefficient(0) - 1.0).abs() < 1e-10);
+        assert!((quotient.coefficient(1) - 1.0).abs() < 1e-10);
+        assert!(remainder.is_zero());
+    }
+
+    #[test]
+    fn test_divide_with_nonzero_remainder() {
+        // (x^2 + 1) / (x - 1) = x + 1, remainder 2
+        let dividend = Polynomial::new(vec![1.0, 0.0, 1.0]);
+        let divisor = Polynomial::new(vec![-1.0, 1.0]);
+        let (quotient, remainder) = dividend.divide(&divisor).unwrap();
+
+        assert!((quotient.coefficient(0) - 1.0).abs() < 1e-10);
+        assert!((quotient.coefficient(1) - 1.0).abs() < 1e-10);
+        assert!(!remainder.is_zero());
+        assert!((remainder.coefficient(0) - 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_divide_rejects_zero_divisor() {
+        let dividend = Polynomial::new(vec![1.0, 1.0]);
+        let zero = Polynomial::new(vec![0.0]);
+        assert!(dividend.divide(&zero).is_err());
+    }
+
+    #[test]
+    fn test_gcd_of_shared_factor() {
+        // (x-1)(x+1) and (x-1)(x+2) share the factor (x-1)
+        let a = Polynomial::new(vec![-1.0, 1.0]).multiply(&Polynomial::new(vec![1.0, 1.0]));
+        let b = Polynomial::new(vec![-1.0, 1.0]).multiply(&Polynomial::new(vec![2.0, 1.0]));
+        let gcd = a.gcd(&b).unwrap();
+
+        // The GCD should exactly divide both inputs.
+        let (_, remainder_a) = a.divide(&gcd).unwrap();
+        let (_, remainder_b) = b.divide(&gcd).unwrap();
+        assert!(remainder_a.is_zero());
+        assert!(remainder_b.is_zero());
+
+        // And it should have degree 1, matching the shared factor (x-1).
+        assert_eq!(gcd.degree(), 1);
+    }
+
+    #[test]
+    fn test_gcd_with_zero_is_the_other_operand() {
+        let a = Polynomial::new(vec![-1.0, 1.0]);
+        let zero = Polynomial::new(vec![0.0]);
+        let gcd = a.gcd(&zero).unwrap();
+
+        let (_, remainder) = a.divide(&gcd).unwrap();
+        assert!(remainder.is_zero());
+    }
+
+    #[test]
+    fn test_evaluation_domain_rounds_up_to_power_of_two() {
+        assert_eq!(EvaluationDomain::new(5).size(), 8);
+        assert_eq!(EvaluationDomain::new(8).size(), 8);
+        assert_eq!(EvaluationDomain::new(1).size(), 1);
+    }
+
+    #[test]
+    fn test_evaluation_domain_round_trips_coefficients() {
+        let domain = EvaluationDomain::new(4);
+        let coeffs: Vec<Float> = vec![1.0, 2.0, 3.0, 4.0]
+            .into_iter()
+            .map(|c| Float::with_val(256, c))
+            .collect();
+
+        let evals = domain.coeffs_to_evals(&coeffs);
+        let round_tripped = domain.evals_to_coeffs(&evals);
+
+        for (original, recovered) in coeffs.iter().zip(round_tripped.iter()) {
+            assert!((original.to_f64() - recovered.to_f64()).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_evaluation_domain_pointwise_multiply_matches_naive() {
+        // (1 + 2x) and (3 + 4x): multiplying in the evaluation domain should
+        // match `Polynomial::multiply`'s schoolbook result once both sides
+        // are transformed back to coefficients.
+        let a = Polynomial::new(vec![1.0, 2.0]);
+        let b = Polynomial::new(vec![3.0, 4.0]);
+        let expected = a.multiply(&b);
+
+        let domain = EvaluationDomain::new(4);
+        let a_coeffs: Vec<Float> = a.to_vec().iter().map(|&c| Float::with_val(256, c)).collect();
+        let b_coeffs: Vec<Float> = b.to_vec().iter().map(|&c| Float::with_val(256, c)).collect();
+        let a_evals = domain.coeffs_to_evals(&a_coeffs);
+        let b_evals = domain.coeffs_to_evals(&b_coeffs);
+        let product_evals: Vec<Complex> = a_evals.iter().zip(b_evals.iter()).map(|(x, y)| x.mul(y)).collect();
+        let product_coeffs = domain.evals_to_coeffs(&product_evals);
+
+        for degree in 0..3 {
+            assert!(
+                (product_coeffs[degree].to_f64() - expected.coefficient(degree as i64)).abs() < 1e-9,
+                "degree {}: domain product {} vs multiply() {}",
+                degree,
+                product_coeffs[degree].to_f64(),
+                expected.coefficient(degree as i64)
+            );
+        }
+    }
+
+    #[test]
+    fn test_interpolate_recovers_a_quadratic() {
+        // y = 1 + 2x + 3x^2, sampled at x = 0, 1, 2
+        let quadratic = Polynomial::new(vec![1.0, 2.0, 3.0]);
+        let points: Vec<(f64, f64)> = [0.0, 1.0, 2.0].iter().map(|&x| (x, quadratic.evaluate(x))).collect();
+
+        let recovered = Polynomial::interpolate(&points).unwrap();
+        assert!((recovered.coefficient(0) - 1.0).abs() < 1e-8);
+        assert!((recovered.coefficient(1) - 2.0).abs() < 1e-8);
+        assert!((recovered.coefficient(2) - 3.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_interpolate_rejects_duplicate_x_values() {
+        let points = vec![(1.0, 2.0), (1.0, 5.0)];
+        assert!(Polynomial::interpolate(&points).is_err());
+    }
+
+    #[test]
+    fn test_evaluate_complex_matches_evaluate_on_the_real_axis() {
+        let poly = Polynomial::new(vec![1.0, 2.0, 3.0]);
+        let x = Complex::new(Float::with_val(256, 2.0), Float::with_val(256, 0.0));
+        let result = poly.evaluate_complex(&x);
+        assert!((result.re.to_f64() - poly.evaluate(2.0)).abs() < 1e-9);
+        assert!(result.im.to_f64().abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_evaluate_complex_handles_negative_exponents() {
+        // x^-2 + 2x^-1 + 3, evaluated at x = 2: 0.25 + 1 + 3 = 4.25
+        let poly = Polynomial::new_laurent(-2, vec![1.0, 2.0, 3.0]);
+        let x = Complex::new(Float::with_val(256, 2.0), Float::with_val(256, 0.0));
+        let result = poly.evaluate_complex(&x);
+        assert!((result.re.to_f64() - 4.25).abs() < 1e-9);
+        assert!(result.im.to_f64().abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_evaluate_at_root_of_unity_matches_evaluate_complex() {
+        let poly = Polynomial::new(vec![1.0, -1.0, 1.0]);
+        let root = root_of_unity(1, 4, FFT_PRECISION, false);
+        let expected = poly.evaluate_complex(&root);
+        let actual = poly.evaluate_at_root_of_unity(1, 4);
+        assert!((actual.re.to_f64() - expected.re.to_f64()).abs() < 1e-9);
+        assert!((actual.im.to_f64() - expected.im.to_f64()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_complex_polynomial_from_real_matches_evaluate_complex() {
+        let poly = Polynomial::new_laurent(-1, vec![1.0, 2.0, 3.0]);
+        let lifted = ComplexPolynomial::from_real(&poly);
+        let x = Complex::new(Float::with_val(256, 1.5), Float::with_val(256, -0.5));
+        let expected = poly.evaluate_complex(&x);
+        let actual = lifted.evaluate(&x);
+        assert!((actual.re.to_f64() - expected.re.to_f64()).abs() < 1e-9);
+        assert!((actual.im.to_f64() - expected.im.to_f64()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_complex_polynomial_add_aligns_by_exponent() {
+        let zero = Float::with_val(256, 0.0);
+        // 2 + 3i at exponent -1
+        let a = ComplexPolynomial::new(-1, vec![Complex::new(Float::with_val(256, 2.0), Float::with_val(256, 3.0))]);
+        // 5 at exponent 0
+        let b = ComplexPolynomial::new(0, vec![Complex::new(Float::with_val(256, 5.0), zero.clone())]);
+        let sum = a.add(&b);
+
+        assert_eq!(sum.min_exponent, -1);
+        assert_eq!(sum.coefficients.len(), 2);
+        assert!((sum.coefficients[0].re.to_f64() - 2.0).abs() < 1e-9);
+        assert!((sum.coefficients[0].im.to_f64() - 3.0).abs() < 1e-9);
+        assert!((sum.coefficients[1].re.to_f64() - 5.0).abs() < 1e-9);
+        assert!(sum.coefficients[1].im.to_f64().abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_complex_polynomial_multiply_sums_min_exponents() {
+        let zero = Float::with_val(256, 0.0);
+        // i at exponent 1
+        let a = ComplexPolynomial::new(1, vec![Complex::new(zero.clone(), Float::with_val(256, 1.0))]);
+        // i at exponent -2
+        let b = ComplexPolynomial::new(-2, vec![Complex::new(zero.clone(), Float::with_val(256, 1.0))]);
+        let product = a.multiply(&b);
+
+        // i * i = -1, at exponent 1 + (-2) = -1
+        assert_eq!(product.min_exponent, -1);
+        assert!((product.coefficients[0].re.to_f64() - (-1.0)).abs() < 1e-9);
+        assert!(product.coefficients[0].im.to_f64().abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_laurent_trefoil_evaluation() {
+        // Trefoil Jones polynomial: -t^-4 + t^-3 + t^-1
+        let trefoil = LaurentPolynomial::new(-4, vec![-1, 1, 0, 1]);
+        assert_eq!(trefoil.min_degree(), -4);
+        assert_eq!(trefoil.max_degree(), -1);
+
+        let expected = |t: f64| -t.powi(-4) + t.powi(-3) + t.powi(-1);
+        assert!((trefoil.evaluate(2.0) - expected(2.0)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_laurent_add_aligns_by_exponent() {
+        let a = LaurentPolynomial::new(-2, vec![1, 2, 3]); // t^-2 + 2t^-1 + 3
+        let b = LaurentPolynomial::new(-1, vec![5, 6]); // 5t^-1 + 6
+
+        let sum = a.add(&b);
+        assert_eq!(sum.coefficient(-2), Integer::from(1));
+        assert_eq!(sum.coefficient(-1), Integer::from(7));
+        assert_eq!(sum.coefficient(0), Integer::from(9));
+    }
+
+    #[test]
+    fn test_laurent_multiply_tracks_min_degree() {
+        let a = LaurentPolynomial::new(-1, vec![1, 1]); // t^-1 + 1
+        let b = LaurentPolynomial::new(1, vec![2, 3]); // 2t + 3t^2
+
+        let product = a.mul(&b);
+        assert_eq!(product.min_degree(), 0);
+        assert_eq!(product.coefficient(0), Integer::from(2));
+        assert_eq!(product.coefficient(1), Integer::from(5));
+        assert_eq!(product.coefficient(2), Integer::from(3));
+    }
+
+    #[test]
+    fn test_laurent_substitute_rational_power() {
+        // A^-4 -> (A=t^-1/4)^-4 = t
+        let poly = LaurentPolynomial::new(-4, vec![1]);
+        let substituted = poly.substitute(-1, 4).unwrap();
+        assert_eq!(substituted.min_degree(), 1);
+        assert_eq!(substituted.coefficient(1), Integer::from(1));
+    }
+
+    #[test]
+    fn test_laurent_substitute_rejects_non_integral_result() {
+        let poly = LaurentPolynomial::new(1, vec![1]); // A^1
+        assert!(poly.substitute(-1, 4).is_err());
+    }
+
+    #[test]
+    fn test_laurent_substitute_rounded() {
+        // A^1 has no exact A -> t^(-1/4) image; rounded result is t^0
+        let poly = LaurentPolynomial::new(1, vec![1]);
+        let substituted = poly.substitute_rounded(-1, 4);
+        assert_eq!(substituted.min_degree(), 0);
+        assert_eq!(substituted.coefficient(0), Integer::from(1));
+    }
+
+    #[test]
+    fn test_laurent_distance() {
+        let a = LaurentPolynomial::new(-1, vec![1, 2, 3]);
+        let b = LaurentPolynomial::new(-1, vec![1, 2, 4]);
+        assert!((a.distance(&b) - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_laurent_div_exact() {
+        // (t^2 - 1) / (t - 1) = t + 1
+        let dividend = LaurentPolynomial::new(0, vec![-1, 0, 1]);
+        let divisor = LaurentPolynomial::new(0, vec![-1, 1]);
+        let quotient = dividend.div_exact(&divisor).unwrap();
+        assert_eq!(quotient.min_degree(), 0);
+        assert_eq!(quotient.coefficient(0), Integer::from(1));
+        assert_eq!(quotient.coefficient(1), Integer::from(1));
+    }
+
+    #[test]
+    fn test_laurent_div_exact_rejects_remainder() {
+        // t^2 + 1 is not divisible by t - 1
+        let dividend = LaurentPolynomial::new(0, vec![1, 0, 1]);
+        let divisor = LaurentPolynomial::new(0, vec![-1, 1]);
+        assert!(dividend.div_exact(&divisor).is_err());
+    }
 }